@@ -29,7 +29,7 @@ fn print_bib(prefix: &str, bib: &Bibliography) {
         .map(|(_, v)| v);
 
     if let Some(year) = year {
-        citation_key = citation_key.replace("2021", &year);
+        citation_key = citation_key.replace("2021", year);
     }
 
     let entry_type = bib.entry_type();
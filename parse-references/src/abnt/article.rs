@@ -3,22 +3,56 @@ use std::fmt::Display;
 use nom_bibtex::Bibliography;
 
 use super::{
-    authors::Authors, date::Date, issue::Issue, join::Join, pages::Pages, strong::Strong, tex,
-    uppercase::Uppercase, volume::Volume, location_publisher::LocationPublisher,
+    authors::Authors,
+    date::Date,
+    edition::Edition,
+    issue::Issue,
+    join::Join,
+    locale,
+    location_publisher::LocationPublisher,
+    pages::{Labeled, Pages, Pagination},
+    strong::Strong,
+    tex,
+    uppercase::title_entry,
+    volume::Volume,
 };
 
+/// The kind of periodical, after the biblatex `entrysubtype`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PeriodicalKind {
+    Journal,
+    /// Newspapers number their volumes by year (`ano 131`) and place the
+    /// section and pages after the date.
+    Newspaper,
+    /// Magazines place the section and pages after the date, like
+    /// newspapers.
+    Magazine,
+}
+
 pub struct Article<'a> {
     author: &'a str,
     title: &'a str,
     subtitle: Option<&'a str>,
     journal: Option<&'a str>,
+    journal_subtitle: Option<&'a str>,
+    periodical: PeriodicalKind,
     location: Option<&'a str>,
     publisher: Option<&'a str>,
     issue: Option<&'a str>,
     volume: Option<&'a str>,
+    part: Option<&'a str>,
+    /// Title of a special issue or dossier, written between the journal
+    /// and the volume.
+    issue_title: Option<&'a str>,
+    issue_subtitle: Option<&'a str>,
+    edition: Option<&'a str>,
+    section: Option<&'a str>,
+    column: Option<&'a str>,
     pages: Option<Pages<'a>>,
-    year: Option<&'a str>,
-    date: Option<&'a str>,
+    pagination: Pagination,
+    /// Electronic identifier used instead of pages: `e20190012`.
+    eid: Option<&'a str>,
+    date: Date<'a>,
 }
 
 impl<'a> Article<'a> {
@@ -28,13 +62,22 @@ impl<'a> Article<'a> {
             title: "",
             subtitle: None,
             journal: None,
+            journal_subtitle: None,
+            periodical: PeriodicalKind::Journal,
             location: None,
             publisher: None,
             issue: None,
             volume: None,
+            part: None,
+            issue_title: None,
+            issue_subtitle: None,
+            edition: None,
+            section: None,
+            column: None,
             pages: None,
-            year: None,
-            date: None,
+            pagination: Pagination::Page,
+            eid: None,
+            date: Date::from_bib(b),
         };
 
         for (k, v) in b.tags().iter() {
@@ -43,17 +86,42 @@ impl<'a> Article<'a> {
                 "title" => article.title = v.trim(),
                 "subtitle" => article.subtitle = Some(v.trim()),
                 "journal" | "journaltitle" => article.journal = Some(v.trim()),
+                "journalsubtitle" => article.journal_subtitle = Some(v.trim()),
+                "entrysubtype" => {
+                    article.periodical = match v.trim() {
+                        "newspaper" => PeriodicalKind::Newspaper,
+                        "magazine" => PeriodicalKind::Magazine,
+                        _ => PeriodicalKind::Journal,
+                    }
+                }
                 "location" | "address" => article.location = Some(v.trim()),
                 "publisher" => article.publisher = Some(v.trim()),
                 "issue" | "number" => article.issue = Some(v.trim()),
                 "volume" => article.volume = Some(v.trim()),
-                "page" | "pages" => article.pages = Some(Pages::from_str(v.trim())),
-                "year" => article.year = Some(v.trim()),
-                "date" => article.date = Some(v.trim()),
+                "part" => article.part = Some(v.trim()),
+                "issuetitle" => article.issue_title = Some(v.trim()),
+                "issuesubtitle" => article.issue_subtitle = Some(v.trim()),
+                "edition" => article.edition = Some(v.trim()),
+                "section" => article.section = Some(v.trim()),
+                "column" => article.column = Some(v.trim()),
+                "eid" => article.eid = Some(v.trim()),
+                "page" | "pages" => {
+                    if let Some((pagination, _)) = Pagination::from_label(v) {
+                        article.pagination = pagination;
+                    }
+
+                    article.pages = Some(Pages::from_str(v.trim()))
+                }
                 _ => continue,
             }
         }
 
+        let pagination = b.tags().iter().find(|(k, _)| k == "pagination");
+
+        if let Some(pagination) = pagination.and_then(|(_, v)| Pagination::from_field(v)) {
+            article.pagination = pagination;
+        }
+
         article
     }
 }
@@ -67,20 +135,46 @@ impl<'a> Display for Article<'a> {
         let mut authors = if self.author.is_empty() {
             String::new()
         } else {
-            format!("{}", Authors(&self.author))
+            format!("{}", Authors(self.author))
         };
 
         if authors.ends_with(".") {
             authors.pop();
         }
 
-        let journal = self.journal.map(|j| Strong(tex::Text(j)));
+        let journal = self.journal.map(|j| match self.journal_subtitle {
+            Some(subtitle) => format!("{}: {}", Strong(tex::Text(j)), tex::Text(subtitle)),
+            None => format!("{}", Strong(tex::Text(j))),
+        });
 
         let issue = self.issue.map(Issue);
 
-        let volume = self.volume.map(Volume);
+        let volume = self.volume.map(|v| match self.periodical {
+            PeriodicalKind::Newspaper => format!("{} {}", locale::terms().year_of_publication, v),
+            _ => format!("{}", Volume(v)),
+        });
+
+        let part = self.part.map(|p| format!("pt. {}", p));
+
+        let issue_title = self.issue_title.map(|t| match self.issue_subtitle {
+            Some(subtitle) => format!("{}: {}", tex::Text(t), tex::Text(subtitle)),
+            None => format!("{}", tex::Text(t)),
+        });
+
+        let pages = self.pages.as_ref().map(|p| Labeled(p, self.pagination));
+
+        let eid = self.eid.filter(|_| self.pages.is_none());
+
+        let edition = self.edition.map(Edition);
+
+        let section = self.section.map(tex::Text);
 
-        let mut date = None;
+        let column = self.column.map(tex::Text);
+
+        // Newspapers and magazines write the section after the date, where
+        // the pages follow it.
+        let placement = self.periodical != PeriodicalKind::Journal
+            && (self.section.is_some() || self.column.is_some() || self.edition.is_some());
 
         let loc_pub = Some(LocationPublisher(
             self.location.unwrap_or(""),
@@ -93,49 +187,61 @@ impl<'a> Display for Article<'a> {
             self.location.as_ref().map(as_dyn_display)
         };
 
+        let date = Some(as_dyn_display(&self.date));
+
+        let pages = pages.as_ref().map(as_dyn_display);
+
+        let placement_parts = [
+            edition.as_ref().map(as_dyn_display),
+            section.as_ref().map(as_dyn_display),
+            column.as_ref().map(as_dyn_display),
+            pages.filter(|_| placement),
+        ];
+
         let mut parts = [
             journal.as_ref().map(as_dyn_display),
             loc_pub,
+            issue_title.as_ref().map(as_dyn_display),
             volume.as_ref().map(as_dyn_display),
+            part.as_ref().map(as_dyn_display),
             issue.as_ref().map(as_dyn_display),
-            self.pages.as_ref().map(as_dyn_display),
-            self.year.as_ref().map(as_dyn_display).or_else(|| {
-                date = self.date.map(Date);
-                date.as_ref().map(as_dyn_display)
-            }),
+            pages.filter(|_| !placement),
+            eid.as_ref().map(as_dyn_display),
+            date,
         ]
         .into_iter()
-        .filter_map(|i| i).peekable();
-
-        let (title_start, title_end) = if self.author.is_empty() {
-            self.title
-                .split_once(tex::match_free_char(' '))
-                .map(|(s, e)| (s, Some(e)))
-                .unwrap_or((self.title, None))
-        } else {
-            ("", Some(self.title))
-        };
+        .flatten()
+        .peekable();
 
-        let title = [title_end, self.subtitle]
+        let mut placement_parts = placement_parts
             .into_iter()
-            .filter_map(|i| i.map(tex::Text));
+            .flatten()
+            .filter(|_| placement)
+            .peekable();
 
         if self.author.is_empty() {
-            if !title_start.is_empty() {
-                write!(f, "{} ", Uppercase(title_start))?;
-            }
+            title_entry(f, self.title)?;
         } else {
-            write!(f, "{}. ", authors)?;
+            write!(f, "{}. {}", authors, tex::Text(self.title))?;
+        }
+
+        if let Some(subtitle) = self.subtitle {
+            write!(f, ": {}", tex::Text(subtitle))?;
         }
 
-        if title_end.is_some() || self.subtitle.is_some() {
-            write!(f, "{}. ", Join::new(": ", title))?;
+        // An untitled article without author starts with the periodical.
+        if !self.author.is_empty() || !self.title.is_empty() || self.subtitle.is_some() {
+            f.write_str(". ")?;
         }
 
         if parts.peek().is_some() {
             write!(f, "{}.", Join::new(", ", parts),)?;
         }
 
+        if placement_parts.peek().is_some() {
+            write!(f, " {}.", Join::new(", ", placement_parts))?;
+        }
+
         Ok(())
     }
 }
@@ -158,13 +264,22 @@ fn simple_article() {
         title: "Os sertões e os (des)caminhos da mudança social no Brasil",
         subtitle: None,
         journal: Some("Tempo Social: Revista de Sociologia da USP"),
+        journal_subtitle: None,
+        periodical: PeriodicalKind::Journal,
         location: Some("São Paulo"),
         publisher: None,
         issue: Some("2"),
         volume: Some("13"),
+        part: None,
+        issue_title: None,
+        issue_subtitle: None,
+        edition: None,
+        section: None,
+        column: None,
         pages: Some(Pages::Range("201", "226")),
-        year: Some("2001"),
-        date: None,
+        pagination: Pagination::Page,
+        eid: None,
+        date: Date::from_year("2001"),
     };
 
     let output = format!("{}", article);
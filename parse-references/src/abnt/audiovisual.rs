@@ -0,0 +1,181 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use super::{
+    date::Date, locale, location_publisher::LocationPublisher, roles::DirectOrder, tex,
+    uppercase::title_entry,
+};
+
+/// Films, videos and episodes, entered by title as in NBR 6023:
+/// `CENTRAL do Brasil. Direção: Walter Salles. Rio de Janeiro: Riofilme,
+/// 1998. 1 DVD (106 min).`
+pub struct Audiovisual<'a> {
+    title: &'a str,
+    subtitle: Option<&'a str>,
+    kind: Option<&'a str>,
+    director: Option<&'a str>,
+    producer: Option<&'a str>,
+    location: &'a str,
+    publisher: &'a str,
+    date: Date<'a>,
+    medium: Option<&'a str>,
+    duration: Option<&'a str>,
+    /// Channel of an online video, the `organization`.
+    channel: Option<&'a str>,
+}
+
+impl<'a> Audiovisual<'a> {
+    pub fn from_bib(b: &'a Bibliography) -> Self {
+        let mut video = Audiovisual {
+            title: "",
+            subtitle: None,
+            kind: None,
+            director: None,
+            producer: None,
+            location: "",
+            publisher: "",
+            date: Date::from_bib(b),
+            medium: None,
+            duration: None,
+            channel: None,
+        };
+
+        let mut online = false;
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "title" => video.title = v.trim(),
+                "subtitle" => video.subtitle = Some(v.trim()),
+                "type" => video.kind = Some(v.trim()),
+                "director" => video.director = Some(v.trim()),
+                "producer" => video.producer = Some(v.trim()),
+                "location" | "address" => video.location = v.trim(),
+                "publisher" => video.publisher = v.trim(),
+                "medium" => video.medium = Some(v.trim()),
+                "duration" => video.duration = Some(v.trim()),
+                "organization" => video.channel = Some(v.trim()),
+                "url" => online = true,
+                _ => continue,
+            }
+        }
+
+        if online && video.medium.is_none() {
+            video.medium = Some("online");
+        }
+
+        video
+    }
+}
+
+impl<'a> Display for Audiovisual<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let terms = locale::terms();
+
+        title_entry(f, self.title)?;
+
+        if let Some(subtitle) = self.subtitle {
+            write!(f, ": {}", tex::Text(subtitle))?;
+        }
+
+        f.write_str(".")?;
+
+        if let Some(kind) = self.kind {
+            let kind = match kind {
+                "documentary" => terms.documentary,
+                "series" => terms.series,
+                "podcast episode" | "podcastepisode" => terms.podcast_episode,
+                kind => kind,
+            };
+
+            write!(f, " {}.", tex::Text(kind))?;
+        }
+
+        match (self.director, self.producer) {
+            (Some(director), Some(producer)) if director == producer => write!(
+                f,
+                " {}: {}.",
+                terms.production_and_direction,
+                DirectOrder(director)
+            )?,
+            (director, producer) => {
+                if let Some(director) = director {
+                    write!(f, " {}: {}.", terms.direction, DirectOrder(director))?;
+                }
+
+                if let Some(producer) = producer {
+                    write!(f, " {}: {}.", terms.production, DirectOrder(producer))?;
+                }
+            }
+        }
+
+        write!(
+            f,
+            " {}, {}.",
+            LocationPublisher(self.location, self.publisher),
+            self.date
+        )?;
+
+        let medium = self.medium.map(|m| match m {
+            "online" => terms.one_video,
+            m => m,
+        });
+
+        match (medium, self.duration) {
+            (Some(medium), Some(duration)) => write!(f, " {} ({}).", tex::Text(medium), duration)?,
+            (Some(medium), None) => write!(f, " {}.", tex::Text(medium))?,
+            (None, Some(duration)) => write!(f, " {}.", duration)?,
+            (None, None) => {}
+        }
+
+        if let Some(channel) = self.channel {
+            write!(f, " {} {}.", terms.published_by_channel, tex::Text(channel))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn film() {
+    let film = Audiovisual {
+        title: "Central do Brasil",
+        subtitle: None,
+        kind: None,
+        director: Some("Salles, Walter"),
+        producer: Some("Clermont-Tonnerre, Martire de and Arthur Cohn"),
+        location: "",
+        publisher: "Riofilme",
+        date: Date::from_year("1998"),
+        medium: Some("1 DVD"),
+        duration: Some("106 min"),
+        channel: None,
+    };
+
+    assert_eq!(
+        format!("{film}"),
+        "CENTRAL do Brasil. Direção: Walter Salles. Produção: Martire de Clermont-Tonnerre e Arthur Cohn. [s.l.]: Riofilme, 1998. 1 DVD (106 min).",
+    );
+}
+
+#[test]
+fn youtube_video() {
+    let video = Audiovisual {
+        title: "Book trailer",
+        subtitle: Some("a luz"),
+        kind: None,
+        director: None,
+        producer: None,
+        location: "",
+        publisher: "",
+        date: Date::from_year("2013"),
+        medium: Some("online"),
+        duration: Some("2 min"),
+        channel: Some("Leia Comigo"),
+    };
+
+    assert_eq!(
+        format!("{video}"),
+        "BOOK trailer: a luz. [s.l.: s.n.], 2013. 1 vídeo (2 min). Publicado pelo canal Leia Comigo.",
+    );
+}
@@ -30,7 +30,7 @@ impl<'a> Display for Authors<'a> {
             write!(f, "; <em>et al</em>")?;
         } else {
             for author in iter {
-                write!(f, "; {}", SurnameFirst(*author))?;
+                write!(f, "; {}", SurnameFirst(author))?;
             }
         }
 
@@ -40,14 +40,10 @@ impl<'a> Display for Authors<'a> {
 
 impl<'a> Display for SurnameFirst<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let (family, given) = if let Some(pair) = self.0.split_once(tex::match_free_char(',')) {
+        let (family, given) = if let Some(pair) = split_name(self.0) {
             pair
         } else {
-            if let Some(i) = self.0.rfind(tex::match_free_char(' ')) {
-                self.0.split_at(i)
-            } else {
-                return write!(f, "{}", Uppercase(self.0));
-            }
+            return write!(f, "{}", Uppercase(self.0));
         };
 
         let mut family_parts = family.split_whitespace().peekable();
@@ -58,8 +54,7 @@ impl<'a> Display for SurnameFirst<'a> {
             if Some(true)
                 == family_parts
                     .peek()
-                    .map(|n| n.chars().next().map(char::is_lowercase))
-                    .flatten()
+                    .and_then(|n| n.chars().next().map(char::is_lowercase))
             {
                 extra_given_parts.push(family_parts.next().unwrap())
             } else {
@@ -79,7 +74,16 @@ impl<'a> Display for SurnameFirst<'a> {
     }
 }
 
-struct Initials<'i>(&'i str);
+/// Splits a name into its family and given parts, either at the first comma
+/// (`Family, Given`) or at the last space (`Given Family`).
+pub fn split_name(name: &str) -> Option<(&str, &str)> {
+    name.split_once(tex::match_free_char(',')).or_else(|| {
+        name.rfind(tex::match_free_char(' '))
+            .map(|i| name.split_at(i))
+    })
+}
+
+pub struct Initials<'i>(pub &'i str);
 
 impl<'i> Display for Initials<'i> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -4,14 +4,21 @@ use nom_bibtex::Bibliography;
 
 use crate::abnt::{authors::Authors, location_publisher::LocationPublisher};
 
-use super::tex;
+use super::{
+    date::Date,
+    description::Description,
+    roles::{Contributors, EditorType},
+    tex,
+};
 
 pub struct Book<'b> {
     title: &'b str,
     subtitle: Option<&'b str>,
     author: &'b str,
-    editor: bool,
-    year: &'b str,
+    editor: Option<EditorType>,
+    contributors: Contributors<'b>,
+    date: Date<'b>,
+    description: Description<'b>,
     location: &'b str,
     publisher: &'b str,
 }
@@ -22,42 +29,36 @@ impl<'a> Book<'a> {
             title: "",
             subtitle: None,
             author: "",
-            editor: false,
+            editor: None,
+            contributors: Contributors::from_bib(b),
             location: "",
             publisher: "",
-            year: "",
+            date: Date::from_bib(b),
+            description: Description::from_bib(b),
         };
 
-        let mut wrong_editor_type = true;
+        let mut editor = None;
 
         for (k, v) in b.tags().iter() {
             match k.as_str() {
                 "author" => book.author = v.as_str(),
-                "editor" => {
-                    book.author = v.as_str();
-                    book.editor = true;
-                }
-                "organizer" => {
-                    book.author = v.as_str();
-                    book.editor = true;
-                    wrong_editor_type = false;
-                }
-                "editortype" => {
-                    if v == "organizer" {
-                        wrong_editor_type = false;
-                    }
-                }
+                "editor" | "organizer" => editor = Some(v.as_str()),
                 "title" => book.title = v.as_str(),
                 "subtitle" => book.subtitle = Some(v.as_str()),
                 "location" | "address" => book.location = v.as_str(),
                 "publisher" => book.publisher = v.as_str(),
-                "year" => book.year = v.as_str(),
                 _ => continue,
             }
         }
 
-        if book.editor && wrong_editor_type {
-            panic!("invalid editor type: expecting organizer, citation_key: {}", b.citation_key())
+        // The editor is the entry element only of a book without authors.
+        match editor {
+            Some(editor) if book.author.is_empty() => {
+                book.author = editor;
+                book.editor = Some(EditorType::from_bib(b));
+            }
+            Some(editor) => book.contributors.editor = Some((editor, EditorType::from_bib(b))),
+            None => {}
         }
 
         book
@@ -66,10 +67,10 @@ impl<'a> Book<'a> {
 
 impl<'b> Display for Book<'b> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.editor {
-            write!(f, "{} (Org.). ", Authors(self.author))?;
+        if let Some(editor_type) = self.editor {
+            write!(f, "{} {}. ", Authors(self.author), editor_type)?;
         } else {
-            let mut authors = format!("{}", Authors(&self.author));
+            let mut authors = format!("{}", Authors(self.author));
             if authors.ends_with(".") {
                 authors.pop();
             }
@@ -86,11 +87,14 @@ impl<'b> Display for Book<'b> {
             f.write_str(". ")?;
         }
 
+        write!(f, "{}", self.contributors)?;
+
         write!(
             f,
-            "{}, {}.",
-            LocationPublisher(&self.location, &self.publisher),
-            self.year,
+            "{}, {}.{}",
+            LocationPublisher(self.location, self.publisher),
+            self.date,
+            self.description,
         )
     }
 }
@@ -101,8 +105,10 @@ fn abreu() {
         title: "Caminhos antigos e povoamento do Brasil",
         subtitle: None,
         author: "Abreu, J. C. d.",
-        editor: false,
-        year: "1988",
+        editor: None,
+        contributors: Contributors::default(),
+        date: Date::from_year("1988"),
+        description: Description::default(),
         location: "Belo Horizonte AND São Paulo",
         publisher: "Itatiaia AND EDUSP",
     };
@@ -115,9 +121,11 @@ fn ackerman() {
     let book = Book {
         title: "Uma História Natural dos sentidos",
         subtitle: None,
-        editor: false,
+        editor: None,
+        contributors: Contributors::default(),
         author: "Ackerman, D.",
-        year: "1990",
+        date: Date::from_year("1990"),
+        description: Description::default(),
         location: "Rio de Janeiro",
         publisher: "Bertrand Brasil",
     };
@@ -4,15 +4,20 @@ use nom_bibtex::Bibliography;
 
 use crate::abnt::authors::Authors;
 
-use super::{tex, location_publisher::LocationPublisher};
+use super::{
+    date::Date, description::Description, location_publisher::LocationPublisher, roles::EditorType,
+    tex,
+};
 
 pub struct Collection<'c> {
     editor: &'c str,
+    editor_type: EditorType,
     title: &'c str,
     subtitle: Option<&'c str>,
     location: &'c str,
     publisher: &'c str,
-    year: &'c str,
+    date: Date<'c>,
+    description: Description<'c>,
 }
 
 impl<'a> Collection<'a> {
@@ -21,36 +26,24 @@ impl<'a> Collection<'a> {
             title: "",
             subtitle: None,
             editor: "",
+            editor_type: EditorType::from_bib(b),
             location: "",
             publisher: "",
-            year: "",
+            date: Date::from_bib(b),
+            description: Description::from_bib(b),
         };
 
-        let mut wrong_editor_type = true;
-
         for (k, v) in b.tags().iter() {
             match k.as_str() {
-                "editor" => collection.editor = v.as_str(),
-                "editortype" => if v == "organizer" {
-                    wrong_editor_type = false;
-                }
-                "organizer" => {
-                    collection.editor = v.as_str();
-                    wrong_editor_type = false;
-                }
+                "editor" | "organizer" => collection.editor = v.as_str(),
                 "title" => collection.title = v.as_str(),
                 "subtitle" => collection.subtitle = Some(v.as_str()),
                 "location" | "address" => collection.location = v.as_str(),
                 "publisher" => collection.publisher = v.as_str(),
-                "year" => collection.year = v.as_str(),
                 _ => continue,
             }
         }
 
-        if wrong_editor_type {
-            panic!("invalid editor type: expecting organizer")
-        }
-
         collection
     }
 }
@@ -59,8 +52,9 @@ impl<'c> Display for Collection<'c> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{} (Org.). <strong>{}</strong>",
-            Authors(&self.editor),
+            "{} {}. <strong>{}</strong>",
+            Authors(self.editor),
+            self.editor_type,
             tex::Text(self.title),
         )?;
 
@@ -70,9 +64,10 @@ impl<'c> Display for Collection<'c> {
 
         write!(
             f,
-            ". {}, {}.",
-            LocationPublisher(&self.location, &self.publisher),
-            self.year,
+            ". {}, {}.{}",
+            LocationPublisher(self.location, self.publisher),
+            self.date,
+            self.description,
         )
     }
 }
@@ -81,11 +76,13 @@ impl<'c> Display for Collection<'c> {
 fn simple_collection() {
     let col = Collection {
         editor: "Lapa, J. R. A.",
+        editor_type: EditorType::Organizer,
         title: "Modos de produção e realidade brasileira",
         subtitle: None,
         location: "Petrópolis",
         publisher: "Vozes",
-        year: "1980",
+        date: Date::from_year("1980"),
+        description: Description::default(),
     };
 
     assert_eq!(
@@ -1,36 +1,528 @@
 use std::fmt::Display;
 
-pub struct Date<'d>(pub &'d str);
+use nom_bibtex::Bibliography;
 
-impl<'d> Display for Date<'d> {
+use crate::macros::month_number;
+
+use super::locale;
+
+/// A calendar date known to the year, the season, the month or the day,
+/// possibly qualified as uncertain (`1850?`) or approximate (`1750~`).
+/// Unknown digits of the year are written as `X` (`197X`, `19XX`). An empty
+/// year stands for a missing date or an open bound of a range.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct Point<'d> {
+    pub year: &'d str,
+    pub month: Option<u8>,
+    pub day: Option<u8>,
+    /// The EDTF season, from `1` for spring (`21`) to `4` for winter (`24`).
+    pub season: Option<u8>,
+    pub uncertain: bool,
+    pub approximate: bool,
+}
+
+/// The date of a reference, which may span a period such as the months
+/// covered by an issue: `jan./mar. 2001`.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct Date<'d> {
+    pub start: Point<'d>,
+    pub end: Option<Point<'d>>,
+    /// `start` and `end` are not a range but the two possible dates:
+    /// `[1971 ou 1972]`.
+    pub alternative: bool,
+    /// A date that is not EDTF, such as the NBR forms `[197-?]` or
+    /// `[ca. 1750]`, written as given. `start` only keeps its year for
+    /// sorting.
+    pub literal: Option<&'d str>,
+}
+
+impl<'d> Point<'d> {
+    /// `2019`, `2019-07` or `2019-07-19`, optionally followed by the EDTF
+    /// qualifiers `?`, `~` or `%`, or nothing when it is not a valid date.
+    /// An unspecified month or day (`2019-XX`, `2019-07-XX`) is left out and
+    /// the months `21` to `24` are the seasons.
+    fn parse(s: &'d str) -> Option<Self> {
+        let s = s.trim();
+
+        let (s, uncertain, approximate) = match s.as_bytes().last() {
+            Some(b'?') => (&s[..s.len() - 1], true, false),
+            Some(b'~') => (&s[..s.len() - 1], false, true),
+            Some(b'%') => (&s[..s.len() - 1], true, true),
+            _ => (s, false, false),
+        };
+
+        // `..` is an open bound, which renders as an unknown one.
+        let s = if s == ".." { "" } else { s };
+
+        let mut parts = s.splitn(3, '-');
+
+        let year = parts.next().unwrap_or("").trim();
+        if !year
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == 'X' || c == 'x')
+        {
+            return None;
+        }
+
+        let year = if year.chars().all(|c| c == 'X' || c == 'x') {
+            ""
+        } else {
+            year
+        };
+        let unspecified = |s: &str| s.eq_ignore_ascii_case("XX");
+
+        let (month, season) = match parts.next().map(str::trim) {
+            Some(m) if unspecified(m) => (None, None),
+            Some(m) => match m.parse().ok()? {
+                m @ 1..=12 => (Some(m), None),
+                s @ 21..=24 => (None, Some(s - 20)),
+                _ => return None,
+            },
+            None => (None, None),
+        };
+        let day = match parts.next().map(str::trim) {
+            Some(d) if unspecified(d) && season.is_none() => None,
+            Some(d) if month.is_some() => Some(d.parse().ok().filter(|d| (1..=31).contains(d))?),
+            Some(_) => return None,
+            None => None,
+        };
+
+        Some(Point {
+            year,
+            month,
+            day,
+            season,
+            uncertain,
+            approximate,
+        })
+    }
+
+    fn is_empty(&self) -> bool {
+        self.year.is_empty()
+    }
+}
+
+impl<'d> Date<'d> {
+    /// An EDTF level 1 date: `1952-07-24`, `1850?`, `1750~`, `197X`, or a
+    /// range of them separated by `/`, whose bounds may be open (`1750/..`)
+    /// or unknown (`1750/`). The EDTF set `[1971,1972]` is one of two dates,
+    /// and `s.d.` an explicitly missing one. Anything else, such as the NBR
+    /// form `[197-?]`, is kept as written.
+    pub fn parse(s: &'d str) -> Self {
+        let s = s.trim();
+
+        if matches!(s, "s.d." | "[s.d.]" | "n.d." | "[n.d.]") {
+            return Date::default();
+        }
+
+        if let Some(bracketed) = s.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            let set = bracketed
+                .split_once(',')
+                .and_then(|(start, end)| Some((Point::parse(start)?, Point::parse(end)?)));
+
+            return match set {
+                Some((start, end)) => Date {
+                    start,
+                    end: Some(end),
+                    alternative: true,
+                    literal: None,
+                },
+                None => Date::literal(s),
+            };
+        }
+
+        let date = match s.split_once('/') {
+            Some((start, end)) => Point::parse(start)
+                .zip(Point::parse(end))
+                .map(|(start, end)| Date {
+                    start,
+                    end: Some(end),
+                    ..Default::default()
+                }),
+            None => Point::parse(s).map(|start| Date {
+                start,
+                ..Default::default()
+            }),
+        };
+
+        date.unwrap_or_else(|| Date::literal(s))
+    }
+
+    /// A date kept as written, sorted by the first year in it.
+    fn literal(s: &'d str) -> Self {
+        let digits = s
+            .find(|c: char| c.is_ascii_digit())
+            .map_or("", |start| &s[start..]);
+        let len = digits
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(digits.len());
+
+        Date {
+            start: Point {
+                year: &digits[..len],
+                ..Default::default()
+            },
+            literal: Some(s),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_year(year: &'d str) -> Self {
+        Date {
+            start: Point {
+                year: year.trim(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Merges the `date` field, which takes precedence, with `year`, `month`
+    /// and `day`. A month range such as `1/3` spans the months of the year.
+    pub fn from_bib(b: &'d Bibliography) -> Self {
+        let mut date = None;
+        let mut year = "";
+        let mut month = None;
+        let mut day = None;
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "date" => date = Some(v.as_str()),
+                "year" => year = v.trim(),
+                "month" => month = Some(v.as_str()),
+                "day" => day = v.trim().parse().ok(),
+                _ => continue,
+            }
+        }
+
+        if let Some(date) = date {
+            return Date::parse(date);
+        }
+
+        // A period of years, which EDTF writes `1760/1821`.
+        let years = year.split_once('-').filter(|(start, end)| {
+            [start, end]
+                .iter()
+                .all(|y| y.len() == 4 && y.chars().all(|c| c.is_ascii_digit()))
+        });
+
+        if let Some((start, end)) = years {
+            return Date {
+                start: Point {
+                    year: start,
+                    ..Default::default()
+                },
+                end: Some(Point {
+                    year: end,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+        }
+
+        let mut date = Date::parse(year);
+
+        if date.end.is_some() || date.literal.is_some() {
+            return date;
+        }
+
+        let months = month.map(|m| m.split_once('/').unwrap_or((m, m)));
+        let months = months.map(|(s, e)| (parse_month(s), parse_month(e)));
+
+        match months {
+            Some((start, end)) if start != end => {
+                date.start.month = start;
+                date.end = Some(Point {
+                    year: date.start.year,
+                    month: end,
+                    ..Default::default()
+                });
+            }
+            Some((start, _)) => {
+                date.start.month = start;
+                date.start.day = day.filter(|_| start.is_some());
+            }
+            None => {}
+        }
+
+        date
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.literal.is_none() && self.start.is_empty() && self.end.is_none_or(|end| end.is_empty())
+    }
+
+    /// The same date without its months and days, as events are dated:
+    /// `2016`, `[ca. 1750]`, `2015-2016`.
+    pub fn years(self) -> Self {
+        let year = |p: Point<'d>| Point {
+            month: None,
+            day: None,
+            season: None,
+            ..p
+        };
+
+        Date {
+            start: year(self.start),
+            end: self.end.map(year),
+            ..self
+        }
+    }
+}
+
+fn parse_month(m: &str) -> Option<u8> {
+    m.trim()
+        .parse()
+        .ok()
+        .or_else(|| month_number(m))
+        .filter(|m| (1..=12).contains(m))
+}
+
+/// The day and month, or the season, of a point, without its year:
+/// `24 jul.`, `primavera`.
+struct DayMonth<'p>(&'p Point<'p>);
+
+impl<'p> Display for DayMonth<'p> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        fn lookup_month(n: u8) -> Option<&'static str> {
-            const MONTHS: [&str; 12] = [
-                "jan", "fev", "mar", "abr", "mai", "jun", "jul", "ago", "set", "out", "nov", "dez",
-            ];
-
-            if n == 0 || n > 12 {
-                None
-            } else {
-                Some(MONTHS[(n - 1) as usize])
+        let terms = locale::terms();
+
+        match (self.0.day, self.0.month, self.0.season) {
+            (Some(day), Some(month), _) => {
+                write!(f, "{} {}", day, terms.months[month as usize - 1])
             }
+            (None, Some(month), _) => write!(f, "{}", terms.months[month as usize - 1]),
+            (_, None, Some(season)) => write!(f, "{}", terms.seasons[season as usize - 1]),
+            _ => Ok(()),
         }
+    }
+}
 
-        let parts: Vec<_> = self.0.split("-").collect();
+/// A point without its qualifiers: `24 jul. 1952`.
+struct Plain<'p>(&'p Point<'p>);
 
-        let year = parts.get(0);
-        let month: Option<&str> = parts
-            .get(1)
-            .map(|s| s.parse::<u8>().ok())
-            .flatten()
-            .map(lookup_month)
-            .flatten();
-        let day: Option<u8> = parts.get(2).map(|s| s.parse().ok()).flatten();
+impl<'p> Display for Plain<'p> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.month.is_some() || self.0.season.is_some() {
+            write!(f, "{} ", DayMonth(self.0))?;
+        }
 
-        if let (Some(year), Some(month), Some(day)) = (year, month, day) {
-            write!(f, "{} {}. {}", day, month, year)
-        } else {
-            write!(f, "{}", self.0)
+        for c in self.0.year.chars() {
+            match c {
+                'X' | 'x' => f.write_str("-")?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'d> Point<'d> {
+    /// Whether the date is an estimate, which ABNT writes between brackets.
+    fn is_qualified(&self) -> bool {
+        self.uncertain || self.approximate || self.year.contains(['X', 'x'])
+    }
+}
+
+/// ABNT brackets inferred dates: `[1850?]`, `[ca. 1750]`, `[197-]`.
+impl<'d> Display for Point<'d> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let terms = locale::terms();
+
+        if self.is_empty() {
+            return write!(f, "[{}]", terms.no_date);
+        }
+
+        if !self.is_qualified() {
+            return write!(f, "{}", Plain(self));
         }
+
+        f.write_str("[")?;
+
+        if self.approximate {
+            write!(f, "{} ", terms.circa)?;
+        }
+
+        write!(f, "{}", Plain(self))?;
+
+        if self.uncertain {
+            f.write_str("?")?;
+        }
+
+        f.write_str("]")
     }
 }
+
+impl<'d> Display for Date<'d> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(literal) = self.literal {
+            return f.write_str(literal);
+        }
+
+        let (start, end) = match self.end {
+            Some(end) if end != self.start => (self.start, end),
+            _ => return write!(f, "{}", self.start),
+        };
+
+        if self.alternative && !start.is_empty() && !end.is_empty() {
+            return write!(
+                f,
+                "[{}{}{}]",
+                Plain(&start),
+                locale::terms().or,
+                Plain(&end)
+            );
+        }
+
+        if start.is_empty() && end.is_empty() {
+            return write!(f, "[{}]", locale::terms().no_date);
+        }
+
+        // Open or unknown bounds are left blank: `1750-`.
+        if start.is_empty() || end.is_empty() {
+            let bound = |p: Point<'d>| {
+                if p.is_empty() {
+                    String::new()
+                } else {
+                    p.to_string()
+                }
+            };
+
+            return write!(f, "{}-{}", bound(start), bound(end));
+        }
+
+        if start.year != end.year || start.is_qualified() || end.is_qualified() {
+            if start.month.is_none() && end.month.is_none() {
+                return write!(f, "{}-{}", start, end);
+            }
+
+            return write!(f, "{}/{}", start, end);
+        }
+
+        // A bound known only to the year spans it, so the range is the year.
+        if (start.month.is_none() && start.season.is_none())
+            || (end.month.is_none() && end.season.is_none())
+        {
+            return write!(f, "{}", start.year);
+        }
+
+        match (start.month == end.month, start.day, end.day) {
+            (true, Some(first), Some(_)) => write!(f, "{}/{}", first, DayMonth(&end))?,
+            _ => write!(f, "{}/{}", DayMonth(&start), DayMonth(&end))?,
+        }
+
+        write!(f, " {}", start.year)
+    }
+}
+
+#[test]
+fn precision() {
+    assert_eq!(format!("{}", Date::parse("1952-07-24")), "24 jul. 1952");
+    assert_eq!(format!("{}", Date::parse("1952-07")), "jul. 1952");
+    assert_eq!(format!("{}", Date::parse("1952")), "1952");
+    assert_eq!(format!("{}", Date::parse("2020-05-14")), "14 maio 2020");
+}
+
+#[test]
+fn ranges() {
+    assert_eq!(
+        format!("{}", Date::parse("2001-01/2001-03")),
+        "jan./mar. 2001"
+    );
+    assert_eq!(
+        format!("{}", Date::parse("2001-07-10/2001-07-15")),
+        "10/15 jul. 2001"
+    );
+    assert_eq!(
+        format!("{}", Date::parse("2000-12/2001-01")),
+        "dez. 2000/jan. 2001"
+    );
+    assert_eq!(format!("{}", Date::parse("2001-07/2001")), "2001");
+    assert_eq!(format!("{}", Date::parse("2001/2001-03")), "2001");
+}
+
+#[test]
+fn merge_fields() {
+    let entries = r"
+    @article{A, year = 2001, month = {1/3}}
+    @article{B, year = 1952, month = 7, day = 24}
+    @article{C, year = 1952, month = 7, date = {1953}}
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entries).expect("valid bibtex");
+
+    let dates: Vec<_> = bibtex
+        .bibliographies()
+        .iter()
+        .map(|b| format!("{}", Date::from_bib(b)))
+        .collect();
+
+    assert_eq!(dates, ["jan./mar. 2001", "24 jul. 1952", "1953"]);
+}
+
+#[test]
+fn edtf() {
+    assert_eq!(format!("{}", Date::parse("1850?")), "[1850?]");
+    assert_eq!(format!("{}", Date::parse("1750~")), "[ca. 1750]");
+    assert_eq!(format!("{}", Date::parse("1750%")), "[ca. 1750?]");
+    assert_eq!(format!("{}", Date::parse("1750/1760")), "1750-1760");
+    assert_eq!(format!("{}", Date::parse("1750~/1760")), "[ca. 1750]-1760");
+    assert_eq!(format!("{}", Date::parse("1750/..")), "1750-");
+    assert_eq!(format!("{}", Date::parse("1750/")), "1750-");
+    assert_eq!(format!("{}", Date::parse("../1760")), "-1760");
+    assert_eq!(format!("{}", Date::parse("../..")), "[s.d.]");
+    assert_eq!(format!("{}", Date::parse("1952-07?")), "[jul. 1952?]");
+    assert_eq!(format!("{}", Date::parse("1984-XX")), "1984");
+    assert_eq!(format!("{}", Date::parse("1984-07-XX")), "jul. 1984");
+    assert_eq!(format!("{}", Date::parse("1984-XX-XX")), "1984");
+    assert_eq!(format!("{}", Date::parse("2001-21")), "primavera 2001");
+    assert_eq!(format!("{}", Date::parse("2001-24")), "inverno 2001");
+    assert_eq!(
+        format!("{}", Date::parse("2001-21/2001-22")),
+        "primavera/verão 2001"
+    );
+    assert_eq!(format!("{}", Date::parse("1984-XX-05")), "1984-XX-05");
+    assert_eq!(format!("{}", Date::parse("2001-25")), "2001-25");
+}
+
+#[test]
+fn inferred() {
+    assert_eq!(format!("{}", Date::parse("[1971,1972]")), "[1971 ou 1972]");
+    assert_eq!(format!("{}", Date::parse("197X")), "[197-]");
+    assert_eq!(format!("{}", Date::parse("197X?")), "[197-?]");
+    assert_eq!(format!("{}", Date::parse("19XX")), "[19--]");
+    assert_eq!(format!("{}", Date::parse("XXXX")), "[s.d.]");
+    assert_eq!(format!("{}", Date::parse("[s.d.]")), "[s.d.]");
+    assert_eq!(format!("{}", Date::from_year("")), "[s.d.]");
+}
+
+#[test]
+fn literal() {
+    assert_eq!(format!("{}", Date::parse("[197-?]")), "[197-?]");
+    assert_eq!(format!("{}", Date::parse("[197-]")), "[197-]");
+    assert_eq!(format!("{}", Date::parse("[19--]")), "[19--]");
+    assert_eq!(format!("{}", Date::parse("[ca. 1750]")), "[ca. 1750]");
+    assert_eq!(format!("{}", Date::parse("1760 ou 1761")), "1760 ou 1761");
+    assert_eq!(Date::parse("[197-?]").start.year, "197");
+    assert_eq!(Date::parse("[ca. 1750]").start.year, "1750");
+    assert_eq!(Date::parse("entre 1906 e 1912").start.year, "1906");
+}
+
+#[test]
+fn year_fields() {
+    let entries = r"
+    @book{A, year = {1760-1821}}
+    @book{B, year = {[197-?]}}
+    @book{C, year = {1952}, month = {13}}
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entries).expect("valid bibtex");
+
+    let dates: Vec<_> = bibtex
+        .bibliographies()
+        .iter()
+        .map(|b| format!("{}", Date::from_bib(b)))
+        .collect();
+
+    assert_eq!(dates, ["1760-1821", "[197-?]", "1952"]);
+}
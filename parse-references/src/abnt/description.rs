@@ -0,0 +1,106 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use super::{pages::Pagination, tex, volume::Volume};
+
+/// The volume cited, the physical description and the series of a book,
+/// written after its date: `3 v. (Coleção Brasiliana, v. 12).`
+#[derive(Default)]
+pub struct Description<'d> {
+    pub volume: Option<&'d str>,
+    pub volumes: Option<&'d str>,
+    pub pagetotal: Option<&'d str>,
+    /// What `pagetotal` counts, after the biblatex `bookpagination` field.
+    pub pagination: Pagination,
+    pub series: Option<&'d str>,
+    pub number: Option<&'d str>,
+}
+
+impl<'d> Description<'d> {
+    pub fn from_bib(b: &'d Bibliography) -> Self {
+        let mut description = Description::default();
+
+        for (k, v) in b.tags().iter() {
+            let v = Some(v.trim()).filter(|v| !v.is_empty());
+
+            match k.as_str() {
+                "volume" => description.volume = v,
+                "volumes" => description.volumes = v,
+                "pagetotal" => description.pagetotal = v,
+                "bookpagination" => {
+                    description.pagination = v.and_then(Pagination::from_field).unwrap_or_default()
+                }
+                "series" => description.series = v,
+                "number" => description.number = v,
+                _ => continue,
+            }
+        }
+
+        description
+    }
+}
+
+impl<'d> Display for Description<'d> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(volume) = self.volume {
+            write!(f, " {}.", Volume(volume))?;
+        } else if let Some(volumes) = self.volumes {
+            write!(f, " {} v.", volumes)?;
+        }
+
+        if let Some(pagetotal) = self.pagetotal {
+            write!(f, " {} {}", pagetotal, self.pagination.abbreviation())?;
+        }
+
+        if let Some(series) = self.series {
+            write!(f, " ({}", tex::Text(series))?;
+
+            if let Some(number) = self.number {
+                write!(f, ", {}", Volume(number))?;
+            }
+
+            f.write_str(").")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn multivolume() {
+    let description = Description {
+        volumes: Some("3"),
+        series: Some("Coleção Brasiliana"),
+        number: Some("12"),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        format!("{description}"),
+        " 3 v. (Coleção Brasiliana, v. 12)."
+    );
+}
+
+#[test]
+fn single_volume() {
+    let description = Description {
+        volume: Some("2"),
+        volumes: Some("3"),
+        pagetotal: Some("250"),
+        ..Default::default()
+    };
+
+    assert_eq!(format!("{description}"), " v. 2. 250 p.");
+}
+
+#[test]
+fn folios() {
+    let description = Description {
+        pagetotal: Some("350"),
+        pagination: Pagination::Folio,
+        ..Default::default()
+    };
+
+    assert_eq!(format!("{description}"), " 350 f.");
+}
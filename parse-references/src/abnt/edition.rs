@@ -0,0 +1,24 @@
+use std::fmt::Display;
+
+/// Edition statement: `2. ed.`, or the edition as written when it is not a
+/// number: `Edição extra`.
+#[repr(transparent)]
+pub struct Edition<'e>(pub &'e str);
+
+impl<'e> Display for Edition<'e> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let edition = self.0.trim();
+
+        if !edition.is_empty() && edition.chars().all(|c| c.is_ascii_digit()) {
+            write!(f, "{}. ed.", edition)
+        } else {
+            write!(f, "{}", super::tex::Text(edition))
+        }
+    }
+}
+
+#[test]
+fn edition() {
+    assert_eq!(format!("{}", Edition("2")), "2. ed.");
+    assert_eq!(format!("{}", Edition("Edição extra")), "Edição extra");
+}
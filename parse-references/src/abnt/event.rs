@@ -0,0 +1,185 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use super::{date::Date, join::Join, tex, uppercase::Uppercase};
+
+/// The event whose proceedings are cited, written as the entry of the
+/// proceedings: `ENCONTRO ESTADUAL DE HISTÓRIA, 17., 2016, Guarabira`.
+pub struct Event<'e> {
+    title: &'e str,
+    number: Option<&'e str>,
+    /// When the event took place, which may differ from the year its
+    /// proceedings were published.
+    date: Date<'e>,
+    /// Where the event took place, the `venue`, or else the `location`.
+    venue: Option<&'e str>,
+}
+
+impl<'e> Event<'e> {
+    /// The event is dated by its `eventdate`, or else its `eventyear`, or
+    /// else the publication date.
+    pub fn from_bib(b: &'e Bibliography) -> Self {
+        let mut event = Event {
+            title: "",
+            number: None,
+            date: Date::default(),
+            venue: None,
+        };
+
+        let mut event_date = None;
+        let mut event_year = None;
+        let mut location = None;
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "eventtitle" => event.title = v.trim(),
+                "number" => event.number = Some(v.trim()),
+                "eventdate" => event_date = Some(Date::parse(v)),
+                "eventyear" => event_year = Some(Date::from_year(v)),
+                "venue" => event.venue = Some(v.trim()),
+                "location" | "address" => location = Some(v.trim()),
+                _ => continue,
+            }
+        }
+
+        event.date = event_date
+            .or(event_year)
+            .unwrap_or_else(|| Date::from_bib(b))
+            .years();
+        event.venue = event.venue.or(location);
+
+        event
+    }
+
+    pub fn date(&self) -> Date<'e> {
+        self.date
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.title.is_empty()
+    }
+}
+
+/// The number of an event as an ordinal: `17.`. Roman numerals are written
+/// in arabic numerals, `XXVIII` as `28.`.
+struct Ordinal<'o>(&'o str);
+
+impl<'o> Display for Ordinal<'o> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.0.is_empty() && self.0.chars().all(|c| c.is_ascii_digit()) {
+            write!(f, "{}.", self.0)
+        } else if let Some(n) = roman(self.0) {
+            write!(f, "{}.", n)
+        } else {
+            write!(f, "{}", tex::Text(self.0))
+        }
+    }
+}
+
+const ROMAN: [(u32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+/// The value of a well-formed roman numeral, in either case.
+fn roman(s: &str) -> Option<u32> {
+    let numeral = s.to_ascii_uppercase();
+    let mut rest = numeral.as_str();
+    let mut value = 0;
+
+    for (n, symbol) in ROMAN {
+        // Only `M`, `C`, `X` and `I` may be repeated, up to three times.
+        let max = if matches!(symbol, "M" | "C" | "X" | "I") {
+            3
+        } else {
+            1
+        };
+
+        for _ in 0..max {
+            match rest.strip_prefix(symbol) {
+                Some(r) => {
+                    rest = r;
+                    value += n;
+                }
+                None => break,
+            }
+        }
+    }
+
+    Some(value).filter(|v| *v > 0 && rest.is_empty())
+}
+
+impl<'e> Display for Event<'e> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts = [
+            self.number.map(|n| Ordinal(n).to_string()),
+            Some(&self.date)
+                .filter(|d| !d.is_empty())
+                .map(Date::to_string),
+            self.venue.map(|v| tex::Text(v).to_string()),
+        ];
+
+        write!(f, "{}", Uppercase(self.title))?;
+
+        let parts = format!("{}", Join::new(", ", parts.into_iter().flatten()));
+
+        if !parts.is_empty() {
+            write!(f, ", {}", parts)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn event() {
+    let event = Event {
+        title: "Encontro Estadual de História",
+        number: Some("17"),
+        date: Date::from_year("2016"),
+        venue: Some("Guarabira"),
+    };
+
+    assert_eq!(
+        format!("{event}"),
+        "ENCONTRO ESTADUAL DE HISTÓRIA, 17., 2016, Guarabira"
+    );
+}
+
+#[test]
+fn event_date_range() {
+    let event = Event {
+        title: "Simpósio Nacional de História",
+        number: Some("XXVIII"),
+        date: Date::parse("2015-07-27/2015-07-31").years(),
+        venue: Some("Florianópolis"),
+    };
+
+    assert_eq!(
+        format!("{event}"),
+        "SIMPÓSIO NACIONAL DE HISTÓRIA, 28., 2015, Florianópolis"
+    );
+}
+
+#[test]
+fn roman_numerals() {
+    assert_eq!(roman("XXVIII"), Some(28));
+    assert_eq!(roman("xiv"), Some(14));
+    assert_eq!(roman("MCMXC"), Some(1990));
+    assert_eq!(roman("IIII"), None);
+    assert_eq!(roman("IC"), None);
+    assert_eq!(roman("Extra"), None);
+    assert_eq!(roman(""), None);
+}
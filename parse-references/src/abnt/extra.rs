@@ -2,13 +2,25 @@ use std::fmt::Display;
 
 use nom_bibtex::Bibliography;
 
-use super::date::Date;
+use crate::{
+    doi::Doi,
+    html::{Anchor, DoiAnchor},
+};
+
+use super::{
+    date::Date,
+    identifier::{strip_label, Eprint},
+    locale,
+};
 
 pub struct ExtraInfo<'w> {
     url: Option<&'w str>,
-    doi: Option<&'w str>,
+    doi: Option<Doi>,
     url_date: Option<&'w str>,
     note: Option<&'w str>,
+    isbn: Option<&'w str>,
+    issn: Option<&'w str>,
+    eprint: Option<Eprint<'w>>,
 }
 
 impl<'w> ExtraInfo<'w> {
@@ -18,56 +30,74 @@ impl<'w> ExtraInfo<'w> {
             doi: None,
             url_date: None,
             note: None,
+            isbn: None,
+            issn: None,
+            eprint: None,
         };
 
+        let mut eprint = None;
+        let mut eprint_type = None;
+
         for (k, v) in b.tags().iter() {
             match k.as_str() {
                 "url" => extra_info.url = Some(v.as_str().trim()),
-                "doi" => {
-                    let doi = v.as_str().trim();
-
-                    let doi = if let Some(doi) = doi.strip_prefix("https://doi.org/") {
-                        doi
-                    } else if let Some(doi) = doi.strip_prefix("http://doi.org/") {
-                        doi
-                    } else {
-                        doi
-                    };
-
-                    extra_info.doi = Some(doi)
-                }
+                "doi" => extra_info.doi = Doi::parse(v),
                 "urldate" => extra_info.url_date = Some(v.as_str().trim()),
                 "note" => extra_info.note = Some(v.as_str().trim().trim_end_matches('.')),
+                "isbn" => extra_info.isbn = Some(strip_label(v, "ISBN")),
+                "issn" => extra_info.issn = Some(strip_label(v, "ISSN")),
+                "eprint" => eprint = Some(v.as_str().trim()),
+                "eprinttype" | "archiveprefix" => eprint_type = Some(v.as_str().trim()),
                 _ => continue,
             }
         }
 
+        extra_info.eprint = eprint
+            .zip(eprint_type)
+            .map(|(id, kind)| Eprint { kind, id });
+
         extra_info
     }
 }
 
 impl<'w> Display for ExtraInfo<'w> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let terms = locale::terms();
+
         if let Some(note) = self.note {
             write!(f, " {}.", note)?;
         }
 
-        if let Some(doi) = self.doi {
+        if let Some(isbn) = self.isbn {
+            write!(f, " ISBN {}.", isbn)?;
+        }
+
+        if let Some(issn) = self.issn {
+            write!(f, " ISSN {}.", issn)?;
+        }
+
+        if let Some(eprint) = &self.eprint {
+            write!(f, " {}.", eprint)?;
+        }
+
+        if let Some(doi) = &self.doi {
             write!(
                 f,
-                r#" Disponível em: <span class="font-mono">&lt;<https://doi.org/{}>&gt;</span>."#,
-                doi,
+                r#" {}: <span class="font-mono">&lt;{}&gt;</span>."#,
+                terms.available_at,
+                DoiAnchor(doi),
             )?;
         } else if let Some(u) = self.url {
             write!(
                 f,
-                r#" Disponível em: <span class="font-mono">&lt;<{}>&gt;</span>."#,
-                u,
+                r#" {}: <span class="font-mono">&lt;{}&gt;</span>."#,
+                terms.available_at,
+                Anchor::url(u),
             )?;
         }
 
         if let Some(d) = self.url_date {
-            write!(f, " Acesso em: {}.", Date(d))?;
+            write!(f, " {}: {}.", terms.accessed_on, Date::parse(d))?;
         }
 
         Ok(())
@@ -81,7 +111,67 @@ fn extra_data() {
         doi: None,
         url_date: Some("2020-12-14"),
         note: Some("Some note"),
+        isbn: None,
+        issn: None,
+        eprint: None,
     };
 
     assert_eq!(format!("{}", extra), " Some note. Acesso em: 14 dez. 2020.",);
 }
+
+#[test]
+fn localized_extra_data() {
+    let extra = ExtraInfo {
+        url: Some("https://example.com"),
+        doi: None,
+        url_date: Some("2020-05-14"),
+        note: None,
+        isbn: None,
+        issn: None,
+        eprint: None,
+    };
+
+    assert_eq!(
+        locale::scoped(Some(locale::Lang::Es), || format!("{}", extra)),
+        r#" Disponible en: <span class="font-mono">&lt;<a href="https://example.com">https://example.com</a>&gt;</span>. Consultado el: 14 mayo 2020."#,
+    );
+}
+
+#[test]
+fn identifiers() {
+    let extra = ExtraInfo {
+        url: None,
+        doi: None,
+        url_date: None,
+        note: None,
+        isbn: Some("978-85-359-0277-8"),
+        issn: None,
+        eprint: Some(Eprint {
+            kind: "hdl",
+            id: "123456789/1234",
+        }),
+    };
+
+    assert_eq!(
+        format!("{}", extra),
+        r#" ISBN 978-85-359-0277-8. Handle: <span class="font-mono">&lt;<a href="https://hdl.handle.net/123456789/1234">https://hdl.handle.net/123456789/1234</a>&gt;</span>."#,
+    );
+}
+
+#[test]
+fn canonical_doi() {
+    let extra = ExtraInfo {
+        url: Some("https://www.scielo.br/j/ts/a/xyz"),
+        doi: Doi::parse("https://dx.doi.org/10.1590/S0103-20702001000200010."),
+        url_date: None,
+        note: None,
+        isbn: None,
+        issn: None,
+        eprint: None,
+    };
+
+    assert_eq!(
+        format!("{}", extra),
+        r#" Disponível em: <span class="font-mono">&lt;<a href="https://doi.org/10.1590/S0103-20702001000200010">https://doi.org/10.1590/S0103-20702001000200010</a>&gt;</span>."#,
+    );
+}
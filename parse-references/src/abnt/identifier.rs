@@ -0,0 +1,165 @@
+use std::fmt::Display;
+
+use crate::html::Anchor;
+
+/// `ISBN-13: 978-85-359-0277-8` without its label, `ISBN`, `ISBN-10`,
+/// `ISBN-13` or `ISSN` in any case, which is written anyway.
+pub fn strip_label<'s>(s: &'s str, label: &str) -> &'s str {
+    let s = s.trim();
+
+    let Some(rest) = s
+        .get(..label.len())
+        .filter(|l| l.eq_ignore_ascii_case(label))
+        .map(|_| &s[label.len()..])
+    else {
+        return s;
+    };
+
+    // The ISBN length only counts when it is part of the label.
+    let rest = ["-10", "-13", "10", "13"]
+        .iter()
+        .filter_map(|n| rest.strip_prefix(n))
+        .find(|r| r.starts_with([':', ' ']))
+        .unwrap_or(rest);
+
+    rest.trim_start().trim_start_matches(':').trim()
+}
+
+/// The digits of an identifier without its label, hyphens and spaces, with
+/// `X` as the value 10.
+fn digits(s: &str, label: &str) -> Option<Vec<u32>> {
+    strip_label(s, label)
+        .chars()
+        .filter(|c| !matches!(c, '-' | ' '))
+        .map(|c| match c {
+            'X' | 'x' => Some(10),
+            c => c.to_digit(10),
+        })
+        .collect()
+}
+
+/// Whether `s` is an ISBN-10 or ISBN-13 with a valid check digit.
+pub fn valid_isbn(s: &str) -> bool {
+    let Some(digits) = digits(s, "ISBN") else {
+        return false;
+    };
+
+    let x_only_last = |n: usize| digits[..n - 1].iter().all(|d| *d < 10);
+
+    match digits.len() {
+        10 if x_only_last(10) => {
+            let sum: u32 = digits.iter().zip((1..=10).rev()).map(|(d, w)| d * w).sum();
+
+            sum.is_multiple_of(11)
+        }
+        13 if digits.iter().all(|d| *d < 10) => {
+            let sum: u32 = digits
+                .iter()
+                .zip([1, 3].iter().cycle())
+                .map(|(d, w)| d * w)
+                .sum();
+
+            sum.is_multiple_of(10)
+        }
+        _ => false,
+    }
+}
+
+/// Whether `s` is an ISSN with a valid check digit.
+pub fn valid_issn(s: &str) -> bool {
+    let Some(digits) = digits(s, "ISSN") else {
+        return false;
+    };
+
+    if digits.len() != 8 || digits[..7].iter().any(|d| *d >= 10) {
+        return false;
+    }
+
+    let sum: u32 = digits.iter().zip((1..=8).rev()).map(|(d, w)| d * w).sum();
+
+    sum.is_multiple_of(11)
+}
+
+/// An identifier in an eprint archive, the biblatex `eprinttype` and
+/// `eprint` fields.
+pub struct Eprint<'e> {
+    pub kind: &'e str,
+    pub id: &'e str,
+}
+
+impl<'e> Eprint<'e> {
+    /// The name of the archive as it is written and where its identifiers
+    /// resolve, when it is known.
+    fn archive(&self) -> Option<(&'static str, &'static str)> {
+        match self.kind.trim().to_lowercase().as_str() {
+            "arxiv" => Some(("arXiv", "https://arxiv.org/abs/")),
+            "handle" | "hdl" => Some(("Handle", "https://hdl.handle.net/")),
+            "hal" => Some(("HAL", "https://hal.science/")),
+            "jstor" => Some(("JSTOR", "https://www.jstor.org/stable/")),
+            "pubmed" | "pmid" => Some(("PubMed", "https://pubmed.ncbi.nlm.nih.gov/")),
+            _ => None,
+        }
+    }
+
+    pub fn is_known(&self) -> bool {
+        self.archive().is_some()
+    }
+}
+
+impl<'e> Display for Eprint<'e> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.archive() {
+            Some((name, resolver)) => write!(
+                f,
+                r#"{}: <span class="font-mono">&lt;{}&gt;</span>"#,
+                name,
+                Anchor::url(&format!("{}{}", resolver, self.id)),
+            ),
+            None => write!(f, "{}: {}", self.kind, self.id),
+        }
+    }
+}
+
+#[test]
+fn isbn() {
+    assert!(valid_isbn("978-85-359-0277-8"));
+    assert!(valid_isbn("ISBN 85-7164-411-X"));
+    assert!(valid_isbn("0-306-40615-2"));
+    assert!(!valid_isbn("978-85-359-0277-7"));
+    assert!(!valid_isbn("0-306-40615"));
+    assert!(valid_isbn("ISBN-13: 978-85-359-0277-8"));
+    assert!(valid_isbn("isbn-10: 85-7164-411-X"));
+    assert!(valid_isbn("ISBN13 978-85-359-0277-8"));
+    assert_eq!(
+        strip_label("ISBN-13: 978-85-359-0277-8", "ISBN"),
+        "978-85-359-0277-8"
+    );
+}
+
+#[test]
+fn issn() {
+    assert!(valid_issn("0378-5955"));
+    assert!(valid_issn("2049-3630"));
+    assert!(valid_issn("ISSN 0317-8471"));
+    assert!(!valid_issn("0378-5954"));
+}
+
+#[test]
+fn eprint() {
+    let arxiv = Eprint {
+        kind: "arxiv",
+        id: "2101.00001",
+    };
+
+    assert_eq!(
+        format!("{arxiv}"),
+        r#"arXiv: <span class="font-mono">&lt;<a href="https://arxiv.org/abs/2101.00001">https://arxiv.org/abs/2101.00001</a>&gt;</span>"#,
+    );
+
+    let other = Eprint {
+        kind: "SciELO",
+        id: "S0102",
+    };
+
+    assert_eq!(format!("{other}"), "SciELO: S0102");
+}
@@ -0,0 +1,249 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use super::{
+    authors::Authors, date::Date, locale, location_publisher::LocationPublisher, strong::Strong,
+    tex, uppercase::title_entry,
+};
+
+/// The iconographic and cartographic documents of NBR 6023.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageKind {
+    /// Photographs, engravings, drawings.
+    Image,
+    Artwork,
+    Map,
+}
+
+/// `KOBAYASHI, K. <strong>Doença dos xavantes</strong>. 1980. 1 fotografia,
+/// color., 16 cm x 56 cm.` Maps also have a publisher and a scale.
+pub struct Image<'i> {
+    kind: ImageKind,
+    author: &'i str,
+    title: &'i str,
+    subtitle: Option<&'i str>,
+    location: Option<&'i str>,
+    publisher: Option<&'i str>,
+    date: Date<'i>,
+    medium: Option<&'i str>,
+    technique: Option<&'i str>,
+    dimensions: Option<&'i str>,
+    scale: Option<&'i str>,
+    /// Collection and institution holding the original.
+    collection: Option<&'i str>,
+    institution: Option<&'i str>,
+}
+
+impl<'i> Image<'i> {
+    pub fn from_bib(b: &'i Bibliography) -> Self {
+        let mut image = Image {
+            kind: match b.entry_type() {
+                "artwork" => ImageKind::Artwork,
+                "map" => ImageKind::Map,
+                _ => ImageKind::Image,
+            },
+            author: "",
+            title: "",
+            subtitle: None,
+            location: None,
+            publisher: None,
+            date: Date::from_bib(b),
+            medium: None,
+            technique: None,
+            dimensions: None,
+            scale: None,
+            collection: None,
+            institution: None,
+        };
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "author" => image.author = v.trim(),
+                "title" => image.title = v.trim(),
+                "subtitle" => image.subtitle = Some(v.trim()),
+                "location" | "address" => image.location = Some(v.trim()),
+                "publisher" => image.publisher = Some(v.trim()),
+                "medium" | "type" => image.medium = Some(v.trim()),
+                "technique" => image.technique = Some(v.trim()),
+                "dimensions" => image.dimensions = Some(v.trim()),
+                "scale" => image.scale = Some(v.trim()),
+                "collection" => image.collection = Some(v.trim()),
+                "institution" | "library" => image.institution = Some(v.trim()),
+                _ => continue,
+            }
+        }
+
+        image
+    }
+}
+
+/// A map scale with its digits grouped: `1:50.000`.
+struct Scale<'s>(&'s str);
+
+impl<'s> Display for Scale<'s> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let separator = locale::terms().thousands_separator;
+
+        let Some((unit, denominator)) = self
+            .0
+            .split_once(':')
+            .filter(|(u, d)| !u.is_empty() && d.chars().all(|c| c.is_ascii_digit()))
+        else {
+            return write!(f, "{}", tex::Text(self.0));
+        };
+
+        write!(f, "{}:", unit)?;
+
+        for (i, c) in denominator.chars().enumerate() {
+            if i > 0 && (denominator.len() - i) % 3 == 0 {
+                write!(f, "{}", separator)?;
+            }
+
+            write!(f, "{}", c)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'i> Display for Image<'i> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let terms = locale::terms();
+
+        let title = match self.subtitle {
+            Some(subtitle) => format!("{}: {}", tex::Text(self.title), tex::Text(subtitle)),
+            None => format!("{}", tex::Text(self.title)),
+        };
+
+        if self.author.is_empty() {
+            title_entry(f, self.title)?;
+
+            if let Some(subtitle) = self.subtitle {
+                write!(f, ": {}", tex::Text(subtitle))?;
+            }
+
+            f.write_str(".")?;
+        } else {
+            let mut authors = format!("{}", Authors(self.author));
+            if authors.ends_with('.') {
+                authors.pop();
+            }
+
+            write!(f, "{}. {}.", authors, Strong(title))?;
+        }
+
+        if self.location.is_some() || self.publisher.is_some() {
+            let loc_pub =
+                LocationPublisher(self.location.unwrap_or(""), self.publisher.unwrap_or(""));
+
+            write!(f, " {}, {}.", loc_pub, self.date)?;
+        } else {
+            write!(f, " {}.", self.date)?;
+        }
+
+        let medium = self.medium.or(match self.kind {
+            ImageKind::Map => Some(terms.one_map),
+            ImageKind::Artwork => Some(terms.one_artwork),
+            ImageKind::Image => None,
+        });
+
+        let description: Vec<_> = [medium, self.technique, self.dimensions]
+            .into_iter()
+            .flatten()
+            .map(|d| format!("{}", tex::Text(d)))
+            .collect();
+
+        if !description.is_empty() {
+            write!(f, " {}.", description.join(", "))?;
+        }
+
+        if let Some(scale) = self.scale {
+            write!(f, " {} {}.", terms.scale, Scale(scale))?;
+        }
+
+        let holding: Vec<_> = [self.collection, self.institution]
+            .into_iter()
+            .flatten()
+            .map(|h| format!("{}", tex::Text(h)))
+            .collect();
+
+        if !holding.is_empty() {
+            write!(f, " {}.", holding.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn photograph() {
+    let image = Image {
+        kind: ImageKind::Image,
+        author: "Kobayashi, K.",
+        title: "Doença dos xavantes",
+        subtitle: None,
+        location: None,
+        publisher: None,
+        date: Date::from_year("1980"),
+        medium: Some("1 fotografia"),
+        technique: Some("p&b"),
+        dimensions: Some("18 x 24 cm"),
+        scale: None,
+        collection: None,
+        institution: None,
+    };
+
+    assert_eq!(
+        format!("{image}"),
+        "KOBAYASHI, K. <strong>Doença dos xavantes</strong>. 1980. 1 fotografia, p&b, 18 x 24 cm.",
+    );
+}
+
+#[test]
+fn colonial_map() {
+    let image = Image {
+        kind: ImageKind::Map,
+        author: "",
+        title: "Carta topographica da capitania do Rio Grande do Norte",
+        subtitle: None,
+        location: None,
+        publisher: None,
+        date: Date::parse("1780~"),
+        medium: None,
+        technique: Some("manuscrito, color."),
+        dimensions: Some("45 x 60 cm"),
+        scale: Some("1:500000"),
+        collection: None,
+        institution: Some("Arquivo Histórico Ultramarino"),
+    };
+
+    assert_eq!(
+        format!("{image}"),
+        "CARTA topographica da capitania do Rio Grande do Norte. [ca. 1780]. 1 mapa, manuscrito, color., 45 x 60 cm. Escala 1:500.000. Arquivo Histórico Ultramarino.",
+    );
+}
+
+#[test]
+fn braced_title() {
+    let image = Image {
+        kind: ImageKind::Map,
+        author: "",
+        title: "{{Rio Grande} do Norte}",
+        subtitle: Some("capitania"),
+        location: None,
+        publisher: None,
+        date: Date::from_year("1780"),
+        medium: None,
+        technique: None,
+        dimensions: None,
+        scale: None,
+        collection: None,
+        institution: None,
+    };
+
+    assert_eq!(
+        format!("{image}"),
+        "RIO GRANDE do Norte: capitania. 1780. 1 mapa.",
+    );
+}
@@ -4,19 +4,30 @@ use nom_bibtex::Bibliography;
 
 use crate::abnt::authors::Authors;
 
-use super::{location_publisher::LocationPublisher, uppercase::Uppercase, tex};
+use super::{
+    date::Date,
+    description::Description,
+    locale,
+    location_publisher::LocationPublisher,
+    roles::{Contributors, EditorType},
+    tex,
+    uppercase::Uppercase,
+};
 
 pub struct InBook<'i> {
     title: &'i str,
     subtitle: Option<&'i str>,
     author: &'i str,
-    year: &'i str,
+    date: Date<'i>,
     publisher: &'i str,
     location: &'i str,
     bookauthor: &'i str,
     booktitle: &'i str,
     booksubtitle: Option<&'i str>,
     editor: Option<&'i str>,
+    editor_type: EditorType,
+    contributors: Contributors<'i>,
+    description: Description<'i>,
 }
 
 impl<'i> InBook<'i> {
@@ -27,11 +38,14 @@ impl<'i> InBook<'i> {
             author: "",
             location: "",
             publisher: "",
-            year: "",
+            date: Date::from_bib(b),
             bookauthor: "",
             booktitle: "",
             booksubtitle: None,
             editor: None,
+            editor_type: EditorType::from_bib(b),
+            contributors: Contributors::from_bib(b),
+            description: Description::from_bib(b),
         };
 
         for (k, v) in b.tags().iter() {
@@ -39,13 +53,12 @@ impl<'i> InBook<'i> {
                 "title" => book.title = v.as_str().trim(),
                 "subtitle" => book.subtitle = Some(v.as_str().trim()),
                 "author" => book.author = v.as_str().trim(),
-                "year" => book.year = v.as_str().trim(),
                 "booktitle" => book.booktitle = v.as_str().trim(),
                 "booksubtitle" => book.booksubtitle = Some(v.as_str().trim()),
                 "bookauthor" => book.bookauthor = v.as_str().trim(),
                 "location" | "address" => book.location = v.as_str().trim(),
                 "publisher" => book.publisher = v.as_str().trim(),
-                "editor" => book.editor = Some(v.as_str().trim()),
+                "editor" | "organizer" => book.editor = Some(v.as_str().trim()),
                 _ => continue,
             }
         }
@@ -56,7 +69,7 @@ impl<'i> InBook<'i> {
 
 impl<'i> Display for InBook<'i> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut authors = format!("{}", Authors(&self.author));
+        let mut authors = format!("{}", Authors(self.author));
         if authors.ends_with(".") {
             authors.pop();
         }
@@ -67,7 +80,7 @@ impl<'i> Display for InBook<'i> {
             write!(f, ": {}", subtitle)?;
         }
 
-        let mut bookauthors = format!("{}", Authors(&self.bookauthor));
+        let mut bookauthors = format!("{}", Authors(self.bookauthor));
         if bookauthors.ends_with(".") {
             bookauthors.pop();
         }
@@ -75,17 +88,31 @@ impl<'i> Display for InBook<'i> {
         if bookauthors.is_empty() {
             if let Some(editor) = self.editor {
                 if !editor.is_empty() {
-                    bookauthors = format!("{} (Org.)", Authors(editor));
+                    bookauthors = format!("{} {}", Authors(editor), self.editor_type);
                 }
             }
         }
 
         if bookauthors.is_empty() {
-            let (title_start, title_end) =
-                self.booktitle.split_once(tex::match_free_char(' ')).unwrap_or((self.title, ""));
-            write!(f, ". In: {} {}.", Uppercase(title_start), title_end)?;
+            let (title_start, title_end) = self
+                .booktitle
+                .split_once(tex::match_free_char(' '))
+                .unwrap_or((self.title, ""));
+            write!(
+                f,
+                ". {}: {} {}.",
+                locale::terms().in_,
+                Uppercase(title_start),
+                title_end
+            )?;
         } else {
-            write!(f, ". In: {}. <strong>{}</strong>", bookauthors, self.booktitle)?;
+            write!(
+                f,
+                ". {}: {}. <strong>{}</strong>",
+                locale::terms().in_,
+                bookauthors,
+                self.booktitle
+            )?;
         }
 
 
@@ -93,9 +120,13 @@ impl<'i> Display for InBook<'i> {
             write!(f, ": {}", booksubtitle)?;
         }
 
-        let loc_pub = LocationPublisher(&*self.location, &*self.publisher);
+        let loc_pub = LocationPublisher(self.location, self.publisher);
 
-        write!(f, ". {}, {}.", loc_pub, self.year)
+        write!(
+            f,
+            ". {}{}, {}.{}",
+            self.contributors, loc_pub, self.date, self.description
+        )
     }
 }
 
@@ -106,13 +137,16 @@ fn simple_inbook() {
         title: "A formação da economia colonial no Rio de Janeiro e de sua primeira elite senhorial (séculos XVI e XVII)",
         subtitle: None,
         author: "Fragoso, J. A.",
-        year: "2001",
+        date: Date::from_year("2001"),
         publisher: "Civilização Brasileira",
         location: "Rio de Janeiro",
         bookauthor: "FRAGOSO, J. and BICALHO, M. F. and GOUVÊA, M. F.",
         booktitle: "O Antigo Regime nos trópicos",
         booksubtitle: Some("a dinâmica Imperial portuguesa (séculos XVI-XVIII)"),
         editor: None,
+        editor_type: EditorType::Editor,
+        contributors: Contributors::default(),
+        description: Description::default(),
     };
 
     assert_eq!(
@@ -2,7 +2,13 @@ use std::fmt::Display;
 
 use nom_bibtex::Bibliography;
 
-use super::{authors::Authors, location_publisher::LocationPublisher};
+use super::{
+    authors::Authors,
+    date::Date,
+    locale,
+    location_publisher::LocationPublisher,
+    roles::{Contributors, EditorType},
+};
 
 pub struct InCollection<'i> {
     author: &'i str,
@@ -11,9 +17,11 @@ pub struct InCollection<'i> {
     booktitle: &'i str,
     booksubtitle: Option<&'i str>,
     editor: &'i str,
+    editor_type: EditorType,
+    contributors: Contributors<'i>,
     location: &'i str,
     publisher: &'i str,
-    year: &'i str,
+    date: Date<'i>,
 }
 
 impl<'i> InCollection<'i> {
@@ -24,52 +32,35 @@ impl<'i> InCollection<'i> {
             author: "",
             location: "",
             publisher: "",
-            year: "",
+            date: Date::from_bib(b),
             editor: "",
+            editor_type: EditorType::from_bib(b),
+            contributors: Contributors::from_bib(b),
             booktitle: "",
             booksubtitle: None,
         };
 
-        let mut wrong_editor_type = true;
-
         for (k, v) in b.tags().iter() {
             match k.as_str() {
                 "title" => collection.title = v.as_str(),
                 "subtitle" => collection.subtitle = Some(v.as_str()),
                 "author" => collection.author = v.as_str(),
-                "year" => collection.year = v.as_str(),
                 "booktitle" => collection.booktitle = v.as_str(),
                 "booksubtitle" => collection.booksubtitle = Some(v.as_str()),
-                "editor" => collection.editor = v.as_str(),
-                "organizer" => {
-                    collection.editor = v.as_str();
-                    wrong_editor_type = false;
-                }
+                "editor" | "organizer" => collection.editor = v.as_str(),
                 "location" | "address" => collection.location = v.as_str(),
                 "publisher" => collection.publisher = v.as_str(),
-                "editortype" => {
-                    if v == "organizer" {
-                        wrong_editor_type = false;
-                    }
-                }
                 _ => continue,
             }
         }
 
-        if wrong_editor_type {
-            panic!(
-                "invalid editor type: expecting organizer, citation_key: {}",
-                b.citation_key()
-            )
-        }
-
         collection
     }
 }
 
 impl<'i> Display for InCollection<'i> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut authors = format!("{}", Authors(&self.author));
+        let mut authors = format!("{}", Authors(self.author));
         if authors.ends_with(".") {
             authors.pop();
         }
@@ -80,10 +71,14 @@ impl<'i> Display for InCollection<'i> {
             write!(f, ": {}", subtitle)?;
         }
 
+        let terms = locale::terms();
+
         write!(
             f,
-            ". In: {} (Org.). <strong>{}</strong>",
-            Authors(&self.editor),
+            ". {}: {} {}. <strong>{}</strong>",
+            terms.in_,
+            Authors(self.editor),
+            self.editor_type,
             self.booktitle
         )?;
 
@@ -91,9 +86,9 @@ impl<'i> Display for InCollection<'i> {
             write!(f, ": {}", booksubtitle)?;
         }
 
-        let loc_pub = LocationPublisher(&*self.location, &*self.publisher);
+        let loc_pub = LocationPublisher(self.location, self.publisher);
 
-        write!(f, ". {}, {}.", loc_pub, self.year)
+        write!(f, ". {}{}, {}.", self.contributors, loc_pub, self.date)
     }
 }
 
@@ -106,9 +101,11 @@ fn simple_incollection() {
         booktitle: "A época moderna e o Brasil colonial",
         booksubtitle: Some("conceitos, fontes e pesquisas"),
         editor: "Silva, G. C. M.",
+        editor_type: EditorType::Organizer,
+        contributors: Contributors::default(),
         location: "Maceió",
         publisher: "EDUFAL",
-        year: "2019",
+        date: Date::from_year("2019"),
     };
 
     assert_eq!(
@@ -116,3 +113,28 @@ fn simple_incollection() {
         "ALVEAL, C. M. O. Uma análise preliminar das sesmarias nas Capitanias do Norte. In: SILVA, G. C. M. (Org.). <strong>A época moderna e o Brasil colonial</strong>: conceitos, fontes e pesquisas. Maceió: EDUFAL, 2019.",
     )
 }
+
+#[test]
+fn translated_incollection() {
+    let incollection = InCollection {
+        author: "Schwartz, S. B.",
+        title: "O Brasil colonial",
+        subtitle: None,
+        booktitle: "História da América Latina",
+        booksubtitle: None,
+        editor: "Bethell, L.",
+        editor_type: EditorType::Organizer,
+        contributors: Contributors {
+            translator: Some("Gomes, Maria Clara Cescato"),
+            ..Default::default()
+        },
+        location: "São Paulo",
+        publisher: "EDUSP",
+        date: Date::from_year("1997"),
+    };
+
+    assert_eq!(
+        format!("{incollection}"),
+        "SCHWARTZ, S. B. O Brasil colonial. In: BETHELL, L. (Org.). <strong>História da América Latina</strong>. Tradução de Maria Clara Cescato Gomes. São Paulo: EDUSP, 1997.",
+    )
+}
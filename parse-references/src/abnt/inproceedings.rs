@@ -2,16 +2,21 @@ use std::fmt::Display;
 
 use nom_bibtex::Bibliography;
 
-use super::{authors::Authors, uppercase::Uppercase, join::Join, tex};
+use super::{
+    authors::Authors,
+    locale,
+    pages::{Labeled, Pages, Pagination},
+    proceedings::Proceedings,
+    tex,
+};
 
 pub struct InProceedings<'i> {
     author: &'i str,
     title: &'i str,
     subtitle: Option<&'i str>,
-    eventtitle: &'i str,
-    number: Option<&'i str>,
-    location: &'i str,
-    year: &'i str,
+    proceedings: Proceedings<'i>,
+    pages: Option<Pages<'i>>,
+    pagination: Pagination,
 }
 
 impl<'i> InProceedings<'i> {
@@ -20,10 +25,9 @@ impl<'i> InProceedings<'i> {
             author: "",
             title: "",
             subtitle: None,
-            eventtitle: "",
-            number: None,
-            location: "",
-            year: "",
+            proceedings: Proceedings::from_bib(b),
+            pages: None,
+            pagination: Pagination::default(),
         };
 
         for (k, v) in b.tags().iter() {
@@ -31,21 +35,30 @@ impl<'i> InProceedings<'i> {
                 "title" => proceeding.title = v.as_str(),
                 "subtitle" => proceeding.subtitle = Some(v.as_str()),
                 "author" => proceeding.author = v.as_str(),
-                "number" => proceeding.number = Some(v.as_str()),
-                "year" | "eventyear" => proceeding.year = v.as_str(),
-                "eventtitle" => proceeding.eventtitle = v.as_str(),
-                "location" | "address" | "venue" => proceeding.location = v.as_str(),
+                "page" | "pages" => {
+                    if let Some((pagination, _)) = Pagination::from_label(v) {
+                        proceeding.pagination = pagination;
+                    }
+
+                    proceeding.pages = Some(Pages::from_str(v.trim()))
+                }
                 _ => continue,
             }
         }
 
+        let pagination = b.tags().iter().find(|(k, _)| k == "pagination");
+
+        if let Some(pagination) = pagination.and_then(|(_, v)| Pagination::from_field(v)) {
+            proceeding.pagination = pagination;
+        }
+
         proceeding
     }
 }
 
 impl<'i> Display for InProceedings<'i> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut authors = format!("{}", Authors(&self.author));
+        let mut authors = format!("{}", Authors(self.author));
         if authors.ends_with(".") {
             authors.pop();
         }
@@ -56,91 +69,99 @@ impl<'i> Display for InProceedings<'i> {
             write!(f, ": {}", tex::Text(subtitle))?;
         }
 
-        let parts = [
-            self.number,
-            Some(self.year),
-            Some(self.location),
-        ].into_iter().filter_map(|s| s.map(tex::Text));
-
-        write!(
-            f,
-            ". In: {}, {}.",
-            Uppercase(self.eventtitle),
-            Join::new(", ", parts),
-        )
+        write!(f, ". {}: {}", locale::terms().in_, self.proceedings)?;
+
+        if let Some(pages) = &self.pages {
+            write!(f, " {}.", Labeled(pages, self.pagination))?;
+        }
+
+        Ok(())
     }
 }
 
 #[test]
 fn simple_inproceedings() {
-    let proc = InProceedings {
-        author: "Dias, P. O.",
-        title: "Gentes de conquista",
-        subtitle: Some("famílias, poder e pecuária na Ribeira do Apodi-Mossoró (1676--1725)"),
-        eventtitle: "Encontro Estadual de História",
-        number: Some("1"),
-        location: "Guarabira, PB",
-        year: "2016",
-    };
+    let bibtex = nom_bibtex::Bibtex::parse(
+        r"
+        @inproceedings{Dias2016gentes,
+          author     = {Dias, P. O.},
+          title      = {Gentes de conquista},
+          subtitle   = {famílias, poder e pecuária na Ribeira do Apodi-Mossoró (1676--1725)},
+          eventtitle = {Encontro Estadual de História},
+          number     = {17},
+          venue      = {Guarabira},
+          eventyear  = {2016},
+          booktitle  = {Anais},
+          location   = {Guarabira},
+          publisher  = {ANPUH-PB},
+          year       = {2016},
+          pages      = {1--15},
+        }
+        ",
+    )
+    .expect("valid bibtex");
+
+    let proc = InProceedings::from_bib(&bibtex.bibliographies()[0]);
 
     assert_eq!(
         format!("{proc}"),
-        "DIAS, P. O. Gentes de conquista: famílias, poder e pecuária na Ribeira do Apodi-Mossoró (1676–1725). In: ENCONTRO ESTADUAL DE HISTÓRIA, 1, 2016, Guarabira, PB."
+        "DIAS, P. O. Gentes de conquista: famílias, poder e pecuária na Ribeira do Apodi-Mossoró (1676–1725). In: ENCONTRO ESTADUAL DE HISTÓRIA, 17., 2016, Guarabira. <strong>Anais</strong> [...]. Guarabira: ANPUH-PB, 2016. p. 1–15."
     )
 }
 
+#[test]
+fn simple_inproceedings2() {
+    let bibtex = nom_bibtex::Bibtex::parse(
+        r"
+        @inproceedings{EcMOTTERTelenovela,
+          author     = {Motter, Maria de Lourdes},
+          title      = {Telenovela},
+          subtitle   = {reflexo e refração na arte do cotidiano},
+          eventtitle = {Congresso Brasileiro de Ciências da Comunicação},
+          number     = {21},
+          venue      = {Recife},
+          eventyear  = {1998},
+          location   = {Recife},
+          year       = {1998},
+        }
+        ",
+    )
+    .expect("valid bibtex");
 
+    let proc = InProceedings::from_bib(&bibtex.bibliographies()[0]);
 
-/*
-
-@inproceedings{Dias2016gentes,
-  author     = {Dias, P. O.},
-  title      = {Gentes de conquista},
-  subtitle   = {famílias, poder e pecuária na Ribeira do Apodi-Mossoró (1676--1725)},
-  eventtitle = {Encontro Estadual de História},
-  number     = {17},
-  venue      = {Guarabira, PB},
-  volume     = {17},
-  number     = {1},
-  eventyear  = {2016},
-  booktitle  = {Anais},
-  location   = {Guarabira, PB},
-  publisher  = {ANPUH-PB},
-  year       = {2016},
-  url        = {http://www.ufpb.br/evento/index.php/xviieeh/xviieeh/paper/view/3189/2709},
+    assert_eq!(
+        format!("{proc}"),
+        "MOTTER, M. d. L. Telenovela: reflexo e refração na arte do cotidiano. In: CONGRESSO BRASILEIRO DE CIÊNCIAS DA COMUNICAÇÃO, 21., 1998, Recife.",
+    )
 }
 
-*/
-
 #[test]
-fn simple_inproceedings2() {
-    let proc = InProceedings {
-        author: "Motter, Maria de Lourdes",
-        title: "Telenovela",
-        subtitle: Some("reflexo e refração na arte do cotidiano"),
-        eventtitle: "Congresso Brasileiro de Ciências da Comunicação",
-        number: Some("21"),
-        location: "Recife",
-        year: "1998",
-    };
+fn columns_of_proceedings() {
+    let bibtex = nom_bibtex::Bibtex::parse(
+        r"
+        @inproceedings{Dias2016gentes,
+          author     = {Dias, P. O.},
+          title      = {Gentes de conquista},
+          eventtitle = {Encontro Estadual de História},
+          number     = {17},
+          venue      = {Guarabira},
+          eventyear  = {2016},
+          booktitle  = {Anais},
+          location   = {Guarabira},
+          publisher  = {ANPUH-PB},
+          year       = {2016},
+          pages      = {1--15},
+          pagination = {column},
+        }
+        ",
+    )
+    .expect("valid bibtex");
+
+    let proc = InProceedings::from_bib(&bibtex.bibliographies()[0]);
 
     assert_eq!(
         format!("{proc}"),
-        "MOTTER, M. d. L. Telenovela: reflexo e refração na arte do cotidiano. In: CONGRESSO BRASILEIRO DE CIÊNCIAS DA COMUNICAÇÃO, 21, 1998, Recife.",
+        "DIAS, P. O. Gentes de conquista. In: ENCONTRO ESTADUAL DE HISTÓRIA, 17., 2016, Guarabira. <strong>Anais</strong> [...]. Guarabira: ANPUH-PB, 2016. col. 1–15."
     )
 }
-
-/*
-@inproceedings{EcMOTTERTelenovela,
-  author     = {Motter, Maria de Lourdes},
-  title      = {Telenovela},
-  subtitle   = {reflexo e refração na arte do cotidiano},
-  eventtitle = {Congresso Brasileiro de Ciências da Comunicação},
-  number     = {21},
-  venue      = {Recife},
-  eventyear  = {1998},
-  location   = {Recife},
-  year       = {1998},
-  url        = {http://www.portcom.intercom.org.br/pdfs/de14671ff94329deb4d1756ec2696184.PDF}
-}
-*/
@@ -0,0 +1,236 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use super::{
+    authors::Authors,
+    date::Date,
+    edition::Edition,
+    locale,
+    location_publisher::LocationPublisher,
+    pages::{Labeled, Pages, Pagination},
+    roles::EditorType,
+    strong::Strong,
+    tex,
+    uppercase::title_entry,
+    volume::Volume,
+};
+
+/// An entry of a dictionary or encyclopedia, usually unsigned and so entered
+/// by its title: `DINARTE de Medeiros Mariz. In: ABREU, A. A. (Coord.).
+/// <strong>Dicionário histórico-biográfico brasileiro</strong>. 2. ed. Rio
+/// de Janeiro: FGV, 2001. v. 3, p. 3512–3514.`
+pub struct InReference<'i> {
+    author: &'i str,
+    title: &'i str,
+    subtitle: Option<&'i str>,
+    booktitle: &'i str,
+    booksubtitle: Option<&'i str>,
+    editor: Option<&'i str>,
+    editor_type: EditorType,
+    edition: Option<&'i str>,
+    location: &'i str,
+    publisher: &'i str,
+    date: Date<'i>,
+    volume: Option<&'i str>,
+    pages: Option<Pages<'i>>,
+    pagination: Pagination,
+}
+
+impl<'i> InReference<'i> {
+    pub fn from_bib(b: &'i Bibliography) -> Self {
+        let mut reference = InReference {
+            author: "",
+            title: "",
+            subtitle: None,
+            booktitle: "",
+            booksubtitle: None,
+            editor: None,
+            editor_type: EditorType::from_bib(b),
+            edition: None,
+            location: "",
+            publisher: "",
+            date: Date::from_bib(b),
+            volume: None,
+            pages: None,
+            pagination: Pagination::default(),
+        };
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "author" => reference.author = v.trim(),
+                "title" => reference.title = v.trim(),
+                "subtitle" => reference.subtitle = Some(v.trim()),
+                "booktitle" => reference.booktitle = v.trim(),
+                "booksubtitle" => reference.booksubtitle = Some(v.trim()),
+                "editor" | "organizer" => reference.editor = Some(v.trim()),
+                "edition" => reference.edition = Some(v.trim()),
+                "location" | "address" => reference.location = v.trim(),
+                "publisher" => reference.publisher = v.trim(),
+                "volume" => reference.volume = Some(v.trim()),
+                "page" | "pages" => {
+                    if let Some((pagination, _)) = Pagination::from_label(v) {
+                        reference.pagination = pagination;
+                    }
+
+                    reference.pages = Some(Pages::from_str(v.trim()))
+                }
+                _ => continue,
+            }
+        }
+
+        let pagination = b.tags().iter().find(|(k, _)| k == "pagination");
+
+        if let Some(pagination) = pagination.and_then(|(_, v)| Pagination::from_field(v)) {
+            reference.pagination = pagination;
+        }
+
+        reference
+    }
+}
+
+impl<'i> Display for InReference<'i> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.author.is_empty() {
+            title_entry(f, self.title)?;
+        } else {
+            let mut authors = format!("{}", Authors(self.author));
+            if authors.ends_with('.') {
+                authors.pop();
+            }
+
+            write!(f, "{}. {}", authors, tex::Text(self.title))?;
+        }
+
+        if let Some(subtitle) = self.subtitle {
+            write!(f, ": {}", tex::Text(subtitle))?;
+        }
+
+        write!(f, ". {}: ", locale::terms().in_)?;
+
+        // A work without editors is entered by its title too.
+        match self.editor.filter(|e| !e.is_empty()) {
+            Some(editor) => write!(
+                f,
+                "{} {}. {}",
+                Authors(editor),
+                self.editor_type,
+                Strong(tex::Text(self.booktitle))
+            )?,
+            None => title_entry(f, self.booktitle)?,
+        }
+
+        if let Some(booksubtitle) = self.booksubtitle {
+            write!(f, ": {}", tex::Text(booksubtitle))?;
+        }
+
+        f.write_str(".")?;
+
+        if let Some(edition) = self.edition {
+            let edition = Edition(edition).to_string();
+
+            write!(f, " {}", edition)?;
+
+            if !edition.ends_with('.') {
+                f.write_str(".")?;
+            }
+        }
+
+        write!(
+            f,
+            " {}, {}.",
+            LocationPublisher(self.location, self.publisher),
+            self.date
+        )?;
+
+        let volume = self.volume.map(|v| Volume(v).to_string());
+        let pages = self
+            .pages
+            .as_ref()
+            .map(|p| Labeled(p, self.pagination).to_string());
+
+        let location: Vec<_> = [volume, pages].into_iter().flatten().collect();
+
+        if !location.is_empty() {
+            write!(f, " {}.", location.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn dictionary_entry() {
+    let reference = InReference {
+        author: "",
+        title: "Dinarte de Medeiros Mariz",
+        subtitle: None,
+        booktitle: "Dicionário histórico-biográfico brasileiro",
+        booksubtitle: None,
+        editor: Some("Abreu, A. A."),
+        editor_type: EditorType::Coordinator,
+        edition: Some("2"),
+        location: "Rio de Janeiro",
+        publisher: "FGV",
+        date: Date::from_year("2001"),
+        volume: Some("3"),
+        pages: Some(Pages::from_str("3512--3514")),
+        pagination: Pagination::Page,
+    };
+
+    assert_eq!(
+        format!("{reference}"),
+        "DINARTE de Medeiros Mariz. In: ABREU, A. A. (Coord.). <strong>Dicionário histórico-biográfico brasileiro</strong>. 2. ed. Rio de Janeiro: FGV, 2001. v. 3, p. 3512–3514.",
+    );
+}
+
+#[test]
+fn unedited_dictionary() {
+    let reference = InReference {
+        author: "",
+        title: "Política",
+        subtitle: None,
+        booktitle: "Dicionário da língua portuguesa",
+        booksubtitle: None,
+        editor: None,
+        editor_type: EditorType::Editor,
+        edition: None,
+        location: "Lisboa",
+        publisher: "Priberam Informática",
+        date: Date::from_year("1998"),
+        volume: None,
+        pages: None,
+        pagination: Pagination::Page,
+    };
+
+    assert_eq!(
+        format!("{reference}"),
+        "POLÍTICA. In: DICIONÁRIO da língua portuguesa. Lisboa: Priberam Informática, 1998.",
+    );
+}
+
+#[test]
+fn columns_of_encyclopedia() {
+    let bibtex = nom_bibtex::Bibtex::parse(
+        r"
+        @inreference{Mariz2001,
+          title      = {Dinarte de Medeiros Mariz},
+          booktitle  = {Dicionário histórico-biográfico brasileiro},
+          location   = {Rio de Janeiro},
+          publisher  = {FGV},
+          year       = {2001},
+          volume     = {3},
+          pages      = {3512--3514},
+          pagination = {column},
+        }
+        ",
+    )
+    .expect("valid bibtex");
+
+    let reference = InReference::from_bib(&bibtex.bibliographies()[0]);
+
+    assert_eq!(
+        format!("{reference}"),
+        "DINARTE de Medeiros Mariz. In: DICIONÁRIO histórico-biográfico brasileiro. Rio de Janeiro: FGV, 2001. v. 3, col. 3512–3514.",
+    );
+}
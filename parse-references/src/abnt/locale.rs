@@ -0,0 +1,325 @@
+use std::cell::Cell;
+
+/// Languages with a table of the fixed strings of a reference.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Lang {
+    PtBr,
+    En,
+    Es,
+}
+
+/// The fixed strings written around the fields of a reference.
+pub struct Terms {
+    /// Heading of the reference list.
+    pub references: &'static str,
+    /// Introduces the container of a part: `In:`.
+    pub in_: &'static str,
+    pub available_at: &'static str,
+    pub accessed_on: &'static str,
+    /// Abbreviated roles of whoever is responsible for a collective work,
+    /// by `editortype`.
+    pub organizer: &'static str,
+    pub editor: &'static str,
+    pub compiler: &'static str,
+    pub coordinator: &'static str,
+    /// Secondary contributors, followed by their names.
+    pub translated_by: &'static str,
+    pub annotated_by: &'static str,
+    pub commented_by: &'static str,
+    pub introduction_by: &'static str,
+    pub foreword_by: &'static str,
+    /// Editors of a work entered by its author or event, by `editortype`:
+    /// `Organização de Ana Souza`.
+    pub organized_by: &'static str,
+    pub edited_by: &'static str,
+    pub compiled_by: &'static str,
+    pub coordinated_by: &'static str,
+    /// Credits of audiovisual works, followed by a colon and the names.
+    pub direction: &'static str,
+    pub production: &'static str,
+    pub production_and_direction: &'static str,
+    /// Credits of sound recordings, followed by a colon and the names.
+    pub composer: &'static str,
+    pub lyrics: &'static str,
+    pub performer: &'static str,
+    /// Position of a song within its album: `faixa 3`.
+    pub track: &'static str,
+    /// Interviews, letters and speeches.
+    pub interview_given_to: &'static str,
+    pub recipient: &'static str,
+    pub letter: &'static str,
+    pub one_letter: &'static str,
+    pub speech: &'static str,
+    pub delivered_at: &'static str,
+    /// Physical description of maps and artworks without a `medium`.
+    pub one_map: &'static str,
+    pub one_artwork: &'static str,
+    /// Introduces the scale of a map: `Escala 1:50.000`.
+    pub scale: &'static str,
+    /// Groups the digits of large numbers: `50.000`.
+    pub thousands_separator: char,
+    /// Kinds of audiovisual works, after the biblatex `type`.
+    pub documentary: &'static str,
+    pub series: &'static str,
+    pub podcast_episode: &'static str,
+    /// Physical description of an online video.
+    pub one_video: &'static str,
+    /// Introduces the channel an online video was published by.
+    pub published_by_channel: &'static str,
+    /// Volume of a newspaper, counted in years: `ano 131`.
+    pub year_of_publication: &'static str,
+    /// Missing place of publication.
+    pub sine_loco: &'static str,
+    /// Missing publisher.
+    pub sine_nomine: &'static str,
+    /// Missing date of publication.
+    pub no_date: &'static str,
+    /// Approximate date: `[ca. 1750]`.
+    pub circa: &'static str,
+    /// Notes about the original edition of a reprint.
+    pub originally_published: &'static str,
+    pub facsimile: &'static str,
+    pub reprint: &'static str,
+    /// Between two possible dates: `[1971 ou 1972]`.
+    pub or: &'static str,
+    /// Separator before the last item of a list.
+    pub and: &'static str,
+    /// Abbreviated month names, including the period when there is one.
+    pub months: [&'static str; 12],
+    /// The EDTF seasons `21` to `24`, from spring to winter.
+    pub seasons: [&'static str; 4],
+}
+
+const PT_BR: Terms = Terms {
+    references: "Referências",
+    in_: "In",
+    available_at: "Disponível em",
+    accessed_on: "Acesso em",
+    organizer: "Org.",
+    editor: "Ed.",
+    compiler: "Comp.",
+    coordinator: "Coord.",
+    translated_by: "Tradução de",
+    annotated_by: "Notas de",
+    commented_by: "Comentários de",
+    introduction_by: "Introdução de",
+    foreword_by: "Prefácio de",
+    organized_by: "Organização de",
+    edited_by: "Edição de",
+    compiled_by: "Compilação de",
+    coordinated_by: "Coordenação de",
+    direction: "Direção",
+    production: "Produção",
+    production_and_direction: "Produção e direção",
+    composer: "Compositor",
+    lyrics: "Letra",
+    performer: "Intérprete",
+    track: "faixa",
+    interview_given_to: "Entrevista concedida a",
+    recipient: "Destinatário",
+    letter: "Carta",
+    one_letter: "1 carta",
+    speech: "Discurso",
+    delivered_at: "proferido em",
+    one_map: "1 mapa",
+    one_artwork: "1 original de arte",
+    scale: "Escala",
+    thousands_separator: '.',
+    documentary: "Documentário",
+    series: "Série",
+    podcast_episode: "Episódio de podcast",
+    one_video: "1 vídeo",
+    published_by_channel: "Publicado pelo canal",
+    year_of_publication: "ano",
+    sine_loco: "s.l.",
+    sine_nomine: "s.n.",
+    no_date: "s.d.",
+    circa: "ca.",
+    originally_published: "Publicado originalmente em",
+    facsimile: "Edição fac-similar",
+    reprint: "Reimpressão",
+    or: " ou ",
+    and: " e ",
+    months: [
+        "jan.", "fev.", "mar.", "abr.", "maio", "jun.", "jul.", "ago.", "set.", "out.", "nov.",
+        "dez.",
+    ],
+    seasons: ["primavera", "verão", "outono", "inverno"],
+};
+
+const EN: Terms = Terms {
+    references: "References",
+    in_: "In",
+    available_at: "Available at",
+    accessed_on: "Accessed on",
+    organizer: "Ed.",
+    editor: "Ed.",
+    compiler: "Comp.",
+    coordinator: "Coord.",
+    translated_by: "Translated by",
+    annotated_by: "Annotated by",
+    commented_by: "Commentary by",
+    introduction_by: "Introduction by",
+    foreword_by: "Foreword by",
+    organized_by: "Organized by",
+    edited_by: "Edited by",
+    compiled_by: "Compiled by",
+    coordinated_by: "Coordinated by",
+    direction: "Director",
+    production: "Producer",
+    production_and_direction: "Producer and director",
+    composer: "Composer",
+    lyrics: "Lyrics",
+    performer: "Performer",
+    track: "track",
+    interview_given_to: "Interview given to",
+    recipient: "Recipient",
+    letter: "Letter",
+    one_letter: "1 letter",
+    speech: "Speech",
+    delivered_at: "delivered at",
+    one_map: "1 map",
+    one_artwork: "1 original artwork",
+    scale: "Scale",
+    thousands_separator: ',',
+    documentary: "Documentary",
+    series: "Series",
+    podcast_episode: "Podcast episode",
+    one_video: "1 video",
+    published_by_channel: "Published by the channel",
+    year_of_publication: "year",
+    sine_loco: "S.l.",
+    sine_nomine: "s.n.",
+    no_date: "n.d.",
+    circa: "ca.",
+    originally_published: "Originally published in",
+    facsimile: "Facsimile edition",
+    reprint: "Reprint",
+    or: " or ",
+    and: " and ",
+    months: [
+        "Jan.", "Feb.", "Mar.", "Apr.", "May", "June", "July", "Aug.", "Sept.", "Oct.", "Nov.",
+        "Dec.",
+    ],
+    seasons: ["Spring", "Summer", "Autumn", "Winter"],
+};
+
+const ES: Terms = Terms {
+    references: "Referencias",
+    in_: "En",
+    available_at: "Disponible en",
+    accessed_on: "Consultado el",
+    organizer: "Org.",
+    editor: "Ed.",
+    compiler: "Comp.",
+    coordinator: "Coord.",
+    translated_by: "Traducción de",
+    annotated_by: "Notas de",
+    commented_by: "Comentarios de",
+    introduction_by: "Introducción de",
+    foreword_by: "Prólogo de",
+    organized_by: "Organización de",
+    edited_by: "Edición de",
+    compiled_by: "Compilación de",
+    coordinated_by: "Coordinación de",
+    direction: "Dirección",
+    production: "Producción",
+    production_and_direction: "Producción y dirección",
+    composer: "Compositor",
+    lyrics: "Letra",
+    performer: "Intérprete",
+    track: "pista",
+    interview_given_to: "Entrevista concedida a",
+    recipient: "Destinatario",
+    letter: "Carta",
+    one_letter: "1 carta",
+    speech: "Discurso",
+    delivered_at: "pronunciado en",
+    one_map: "1 mapa",
+    one_artwork: "1 original de arte",
+    scale: "Escala",
+    thousands_separator: '.',
+    documentary: "Documental",
+    series: "Serie",
+    podcast_episode: "Episodio de pódcast",
+    one_video: "1 vídeo",
+    published_by_channel: "Publicado por el canal",
+    year_of_publication: "año",
+    sine_loco: "S.l.",
+    sine_nomine: "s.n.",
+    no_date: "s.f.",
+    circa: "ca.",
+    originally_published: "Publicado originalmente en",
+    facsimile: "Edición facsímil",
+    reprint: "Reimpresión",
+    or: " o ",
+    and: " y ",
+    months: [
+        "ene.", "feb.", "mar.", "abr.", "mayo", "jun.", "jul.", "ago.", "sept.", "oct.", "nov.",
+        "dic.",
+    ],
+    seasons: ["primavera", "verano", "otoño", "invierno"],
+};
+
+thread_local! {
+    static DEFAULT: Cell<Lang> = const { Cell::new(Lang::PtBr) };
+    static CURRENT: Cell<Option<Lang>> = const { Cell::new(None) };
+}
+
+impl Lang {
+    /// Accepts BCP 47 tags (`pt-BR`, `en-US`) as well as the BibLaTeX
+    /// `langid` names (`brazilian`, `english`, `spanish`...).
+    pub fn from_tag(tag: &str) -> Option<Lang> {
+        let tag = tag.trim().to_lowercase();
+        let primary = tag.split(['-', '_']).next().unwrap_or("");
+
+        match primary {
+            "pt" | "brazil" | "brazilian" | "portuguese" | "portuges" => Some(Lang::PtBr),
+            "en" | "english" | "american" | "british" | "usenglish" | "ukenglish" => Some(Lang::En),
+            "es" | "spanish" => Some(Lang::Es),
+            _ => None,
+        }
+    }
+
+    pub fn terms(self) -> &'static Terms {
+        match self {
+            Lang::PtBr => &PT_BR,
+            Lang::En => &EN,
+            Lang::Es => &ES,
+        }
+    }
+}
+
+/// Sets the language used by entries without a `langid`.
+pub fn set_default(lang: Lang) {
+    DEFAULT.with(|d| d.set(lang));
+}
+
+pub fn current() -> Lang {
+    CURRENT
+        .with(Cell::get)
+        .unwrap_or_else(|| DEFAULT.with(Cell::get))
+}
+
+/// The terms of the language of the entry being written.
+pub fn terms() -> &'static Terms {
+    current().terms()
+}
+
+/// Runs `f` with `lang` as the current language, if there is one.
+pub fn scoped<T>(lang: Option<Lang>, f: impl FnOnce() -> T) -> T {
+    let previous = CURRENT.with(|c| c.replace(lang.or_else(|| c.get())));
+    let result = f();
+    CURRENT.with(|c| c.set(previous));
+
+    result
+}
+
+#[test]
+fn lang_from_tag() {
+    assert_eq!(Lang::from_tag("pt-BR"), Some(Lang::PtBr));
+    assert_eq!(Lang::from_tag("brazilian"), Some(Lang::PtBr));
+    assert_eq!(Lang::from_tag("en-US"), Some(Lang::En));
+    assert_eq!(Lang::from_tag("Spanish"), Some(Lang::Es));
+    assert_eq!(Lang::from_tag("latin"), None);
+}
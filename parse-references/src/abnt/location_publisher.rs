@@ -2,7 +2,7 @@ use std::fmt::Display;
 
 use crate::abnt::join::{Join, JoinAnd};
 
-use super::{AND_REGEX, tex};
+use super::{locale, tex, AND_REGEX};
 
 pub struct LocationPublisher<'a>(pub &'a str, pub &'a str);
 
@@ -10,10 +10,12 @@ struct SingleLocPub<'a>(pub &'a str, pub &'a str);
 
 impl<'a> Display for SingleLocPub<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let terms = locale::terms();
+
         match (self.0.is_empty(), self.1.is_empty()) {
-            (true, true) => write!(f, "[s.l.: s.n.]"),
-            (true, false) => write!(f, "[s.l.]: {}", tex::Text(self.1)),
-            (false, true) => write!(f, "{}: [s.n.]", tex::Text(self.0)),
+            (true, true) => write!(f, "[{}: {}]", terms.sine_loco, terms.sine_nomine),
+            (true, false) => write!(f, "[{}]: {}", terms.sine_loco, tex::Text(self.1)),
+            (false, true) => write!(f, "{}: [{}]", tex::Text(self.0), terms.sine_nomine),
             (false, false) => write!(f, "{}: {}", tex::Text(self.0), tex::Text(self.1)),
         }
     }
@@ -33,11 +35,13 @@ impl<'a> Display for LocationPublisher<'a> {
 
             write!(f, "{}", Join::new("; ", loc_pub))
         } else {
+            let and = locale::terms().and;
+
             write!(
                 f,
                 "{}: {}",
-                JoinAnd::new(", ", " e ", locations.into_iter()),
-                JoinAnd::new(", ", " e ", publishers.into_iter()),
+                JoinAnd::new(", ", and, locations.into_iter()),
+                JoinAnd::new(", ", and, publishers.into_iter()),
             )
         }
     }
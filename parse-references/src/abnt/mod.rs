@@ -1,31 +1,64 @@
-use std::{borrow::Cow, cmp::Ordering, fmt::Display};
+use std::{cell::Cell, cmp::Ordering, fmt::Display};
 
 use lazy_static::lazy_static;
 use nom_bibtex::Bibliography;
 use regex::{Regex, RegexBuilder};
 
-use crate::abnt::extra::ExtraInfo;
+use crate::abnt::{date::Date, extra::ExtraInfo};
+use crate::doi::Doi;
 
 use self::{
-    article::Article, authors::Authors, book::Book, collection::Collection, inbook::InBook,
-    incollection::InCollection, inproceedings::InProceedings, thesis::Thesis,
+    article::Article,
+    audiovisual::Audiovisual,
+    authors::Authors,
+    book::Book,
+    collection::Collection,
+    image::Image,
+    inbook::InBook,
+    incollection::InCollection,
+    inproceedings::InProceedings,
+    inreference::InReference,
+    locale::Lang,
+    online::Online,
+    original::Original,
+    periodical::Periodical,
+    personal::{Personal, PersonalKind},
+    proceedings::Proceedings,
+    roles::EditorType,
+    sound::Sound,
+    thesis::Thesis,
 };
 
 mod article;
-mod authors;
+mod audiovisual;
+pub mod authors;
 mod book;
 mod collection;
 mod date;
+mod description;
+mod edition;
+mod event;
 mod extra;
+mod identifier;
+mod image;
 mod inbook;
 mod incollection;
 mod inproceedings;
+mod inreference;
 mod issue;
-mod join;
+pub mod join;
+pub mod locale;
 mod location_publisher;
-mod pages;
+mod online;
+mod original;
+pub mod pages;
+mod periodical;
+mod personal;
+mod proceedings;
+mod roles;
+mod sound;
 mod strong;
-mod tex;
+pub mod tex;
 mod thesis;
 mod uppercase;
 mod volume;
@@ -37,40 +70,83 @@ lazy_static! {
         .unwrap();
 }
 
+thread_local! {
+    static SORT_BY_ORIGDATE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Sorts works by the same author by their original date, when they have
+/// one, instead of the date of the edition cited.
+pub fn sort_by_origdate(enabled: bool) {
+    SORT_BY_ORIGDATE.with(|s| s.set(enabled));
+}
+
 #[repr(transparent)]
 #[derive(PartialEq, Eq)]
 pub struct Abnt<'b>(pub &'b Bibliography);
 
 impl<'b> PartialOrd for Abnt<'b> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        fn find_sort_keys<'a>(b: &'a Bibliography) -> (Option<Cow<'a, str>>, Option<&'a str>) {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'b> Ord for Abnt<'b> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        fn find_sort_keys(b: &Bibliography) -> (Option<String>, Option<&str>) {
             let mut sort_title = None;
             let mut author = None;
             let mut editor = None;
+            let mut composer = None;
+            let mut event = None;
             let mut title = None;
-            let mut year = None;
+            let mut orig_date = None;
 
             for (k, v) in b.tags() {
                 match k.as_str() {
                     "sorttitle" => sort_title = Some(v),
                     "author" => author = Some(v),
-                    "editor" => editor = Some(v),
+                    "editor" | "organizer" => editor = Some(v),
+                    "composer" => composer = Some(v),
+                    "eventtitle" => event = Some(v),
                     "title" => title = Some(v),
-                    "year" => year = Some(v.trim()),
+                    "journal" | "journaltitle" if title.is_none() => title = Some(v),
+                    "origdate" if SORT_BY_ORIGDATE.with(Cell::get) => {
+                        orig_date = Some(Date::parse(v))
+                    }
                     _ => {}
                 }
             }
 
+            let date = orig_date.unwrap_or_else(|| Date::from_bib(b));
+
+            // The entry element the reference is printed under: only whole
+            // books and collections are entered by their editors.
+            let names = match b.entry_type() {
+                _ if PersonalKind::from_bib(b).is_some() => author,
+                "book" | "mvbook" | "reference" | "mvreference" | "collection" | "mvcollection" => {
+                    author.or(editor)
+                }
+                "audio" | "music" => author.or(composer),
+                "periodical" | "movie" | "video" => None,
+                _ => author,
+            };
+
+            // Proceedings are entered by their event.
+            let title = match b.entry_type() {
+                "proceedings" => event.or(title),
+                _ => title,
+            };
+
+            let entry = match sort_title {
+                Some(s) => Some(s.trim().to_string()),
+                None => names
+                    .map(|a| format!("{}", Authors(a)))
+                    .or_else(|| title.map(|t| format!("{}", tex::NoBraces(t.trim())))),
+            };
+
             (
-                sort_title
-                    .map(|s| Cow::Borrowed(s.trim()))
-                    .or_else(|| {
-                        author
-                            .or(editor)
-                            .map(|a| Cow::Owned(format!("{}", Authors(a))))
-                    })
-                    .or_else(|| title.map(|t| Cow::Borrowed(t.trim()))),
-                year,
+                entry.map(|e| e.to_lowercase()),
+                Some(date.start.year).filter(|y| !y.is_empty()),
             )
         }
 
@@ -78,32 +154,134 @@ impl<'b> PartialOrd for Abnt<'b> {
         let (other_sort_key, other_year) = find_sort_keys(other.0);
 
         match self_sort_key.cmp(&other_sort_key) {
-            Ordering::Equal => Some(self_year.cmp(&other_year)),
-            o => Some(o),
+            Ordering::Equal => self_year.cmp(&other_year),
+            o => o,
         }
     }
 }
 
-impl<'b> Ord for Abnt<'b> {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+/// Warns about what the references of `b` will be missing or get wrong.
+pub fn lint(b: &Bibliography) {
+    let key = b.citation_key();
+
+    if b.entry_type() != "online" && Date::from_bib(b).is_empty() {
+        eprintln!("warning: {} has no date, written as [s.d.]", key);
+    }
+
+    if b.entry_type() == "online" {
+        for field in ["url", "urldate"] {
+            if !b.tags().iter().any(|(k, _)| k == field) {
+                eprintln!("warning: {} is online but has no {}", key, field);
+            }
+        }
+    }
+
+    if let Some((_, t)) = b.tags().iter().find(|(k, _)| k == "editortype") {
+        if EditorType::parse(t).is_none() {
+            eprintln!(
+                "warning: {} has an unknown editortype {}, using editor",
+                key,
+                t.trim()
+            );
+        }
+    }
+
+    if PersonalKind::from_bib(b).is_some() && !b.tags().iter().any(|(k, _)| k == "author") {
+        eprintln!("warning: {} has no author, entered by its title", key);
+    }
+
+    let mut eprint = None;
+    let mut eprint_type = None;
+
+    for (k, v) in b.tags() {
+        match k.as_str() {
+            "isbn" if !identifier::valid_isbn(v) => {
+                eprintln!("warning: {} has an invalid ISBN {}", key, v.trim())
+            }
+            "issn" if !identifier::valid_issn(v) => {
+                eprintln!("warning: {} has an invalid ISSN {}", key, v.trim())
+            }
+            "doi" if Doi::parse(v).is_none() => {
+                eprintln!("warning: {} has an invalid DOI {}, left out", key, v.trim())
+            }
+            "eprint" => eprint = Some(v.as_str()),
+            "eprinttype" | "archiveprefix" => eprint_type = Some(v.as_str()),
+            _ => {}
+        }
+    }
+
+    match (eprint, eprint_type) {
+        (Some(id), Some(kind)) if !identifier::Eprint { kind, id }.is_known() => {
+            eprintln!(
+                "warning: {} has an unknown eprinttype {}, written without a link",
+                key,
+                kind.trim()
+            )
+        }
+        (Some(_), None) => eprintln!("warning: {} has an eprint without an eprinttype", key),
+        _ => {}
     }
 }
 
 impl<'b> Display for Abnt<'b> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.0.entry_type() {
-            "article" | "online" | "movie" | "misc" => write!(f, "{}", Article::from_bib(&self.0)),
-            "book" => write!(f, "{}", Book::from_bib(&self.0)),
-            "thesis" => write!(f, "{}", Thesis::from_bib(&self.0)),
-            "inbook" => write!(f, "{}", InBook::from_bib(&self.0)),
-            "incollection" => write!(f, "{}", InCollection::from_bib(&self.0)),
-            "inproceedings" => write!(f, "{}", InProceedings::from_bib(&self.0)),
-            "collection" => write!(f, "{}", Collection::from_bib(&self.0)),
-            _ => panic!("unexpected type: {}", self.0.entry_type()),
-        }?;
+        let lang = self
+            .0
+            .tags()
+            .iter()
+            .find(|(k, _)| k == "langid")
+            .and_then(|(_, v)| Lang::from_tag(v));
+
+        locale::scoped(lang, || {
+            if let Some(kind) = PersonalKind::from_bib(self.0) {
+                write!(f, "{}", Personal::from_bib(kind, self.0))
+            } else {
+                match self.0.entry_type() {
+                    "online" => write!(
+                        f,
+                        "{}{}",
+                        Online::from_bib(self.0),
+                        Original::from_bib(self.0)
+                    ),
+                    "movie" | "video" => write!(
+                        f,
+                        "{}{}",
+                        Audiovisual::from_bib(self.0),
+                        Original::from_bib(self.0)
+                    ),
+                    "image" | "artwork" | "map" => write!(f, "{}", Image::from_bib(self.0)),
+                    "audio" | "music" => write!(f, "{}", Sound::from_bib(self.0)),
+                    "article" | "misc" => write!(
+                        f,
+                        "{}{}",
+                        Article::from_bib(self.0),
+                        Original::from_bib(self.0)
+                    ),
+                    "periodical" => write!(f, "{}", Periodical::from_bib(self.0)),
+                    "book" | "mvbook" | "reference" | "mvreference" => write!(
+                        f,
+                        "{}{}",
+                        Book::from_bib(self.0),
+                        Original::from_bib(self.0)
+                    ),
+                    "thesis" => write!(f, "{}", Thesis::from_bib(self.0)),
+                    "inbook" => write!(
+                        f,
+                        "{}{}",
+                        InBook::from_bib(self.0),
+                        Original::from_bib(self.0)
+                    ),
+                    "incollection" => write!(f, "{}", InCollection::from_bib(self.0)),
+                    "inreference" => write!(f, "{}", InReference::from_bib(self.0)),
+                    "inproceedings" => write!(f, "{}", InProceedings::from_bib(self.0)),
+                    "proceedings" => write!(f, "{}", Proceedings::from_bib(self.0)),
+                    "collection" => write!(f, "{}", Collection::from_bib(self.0)),
+                    _ => panic!("unexpected type: {}", self.0.entry_type()),
+                }
+            }?;
 
-        write!(f, "{}", ExtraInfo::from_bib(&self.0))
+            write!(f, "{}", ExtraInfo::from_bib(self.0))
+        })
     }
 }
 
@@ -122,13 +300,12 @@ fn article_from_bib() {
 
     let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
 
-    for bib in bibtex.bibliographies() {
-        assert_eq!(
-            format!("{}", Abnt(bib)),
-            "AZEVEDO, A. Aldeias e aldeamentos. <strong>Boletim Paulista de Geografia</strong>, n. 33, p. 27, 1959.",
-        );
-        break;
-    }
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "AZEVEDO, A. Aldeias e aldeamentos. <strong>Boletim Paulista de Geografia</strong>, n. 33, p. 27, 1959.",
+    );
 }
 
 #[test]
@@ -147,13 +324,12 @@ fn article_from_bib_3() {
 
     let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
 
-    for bib in bibtex.bibliographies() {
-        assert_eq!(
-            format!("{}", Abnt(bib)),
-            "CHATEAUBRIAND, A. O “show” de Jacques Fath. <strong>Diário de Natal</strong>, Natal, p. 3, 24 jul. 1952.",
-        );
-        break;
-    }
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "CHATEAUBRIAND, A. O “show” de Jacques Fath. <strong>Diário de Natal</strong>, Natal, p. 3, 24 jul. 1952.",
+    );
 }
 
 #[test]
@@ -170,13 +346,12 @@ fn article_from_bib_4() {
 
     let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
 
-    for bib in bibtex.bibliographies() {
-        assert_eq!(
-            format!("{}", Abnt(bib)),
-            "<strong>Diário de Natal</strong>, Natal, p. 5, 10 jul. 1949.",
-        );
-        break;
-    }
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "<strong>Diário de Natal</strong>, Natal, p. 5, 10 jul. 1949.",
+    );
 }
 
 #[test]
@@ -191,13 +366,12 @@ fn article_from_bib_5() {
 
     let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
 
-    for bib in bibtex.bibliographies() {
-        assert_eq!(
-            format!("{}", Abnt(bib)),
-            r#"DINARTE de Medeiros Mariz | CPDOC.  Disponível em: <span class="font-mono">&lt;<a href="http://www.fgv.br/cpdoc/acervo/dicionarios/verbete-biografico/dinarte-de-medeiros-mariz">http://www.fgv.br/cpdoc/acervo/dicionarios/verbete-biografico/dinarte-de-medeiros-mariz</a>&gt;</span>. Acesso em: 19 jul. 2019."#,
-        );
-        break;
-    }
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        r#"DINARTE de Medeiros Mariz | CPDOC. Disponível em: <span class="font-mono">&lt;<a href="http://www.fgv.br/cpdoc/acervo/dicionarios/verbete-biografico/dinarte-de-medeiros-mariz">http://www.fgv.br/cpdoc/acervo/dicionarios/verbete-biografico/dinarte-de-medeiros-mariz</a>&gt;</span>. Acesso em: 19 jul. 2019."#,
+    );
 }
 
 #[test]
@@ -212,20 +386,77 @@ fn article_from_bib_6() {
 
     let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
 
-    for bib in bibtex.bibliographies() {
-        assert_eq!(
-            format!("{}", Abnt(bib)),
-            r#"RELATÓRIODO Departamento Nacional de Obras Contra as Secas (DNOCS). 1976. Arquivo da Paróquia da Diocese de Caicó."#,
-        );
-        break;
-    }
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        r#"RELATÓRIODO Departamento Nacional de Obras Contra as Secas (DNOCS). 1976. Arquivo da Paróquia da Diocese de Caicó."#,
+    );
+}
+
+#[test]
+fn newspaper_section() {
+    let entry = "
+    @article{Folha1999Lagos,
+        title        = {Lagos andinos dão banho de beleza},
+        journal      = {Folha de S. Paulo},
+        location     = {São Paulo},
+        volume       = {79},
+        number       = {25.647},
+        date         = {1999-06-28},
+        section      = {Folha Turismo},
+        column       = {Caderno 8},
+        pages        = {13},
+        entrysubtype = {newspaper}
+      }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "LAGOS andinos dão banho de beleza. <strong>Folha de S. Paulo</strong>, São Paulo, ano 79, n. 25.647, 28 jun. 1999. Folha Turismo, Caderno 8, p. 13.",
+    );
+}
+
+#[test]
+fn magazine_eid() {
+    let entry = "
+    @article{Souza2020,
+        author          = {Souza, A.},
+        title           = {Sertões},
+        journal         = {Revista de História},
+        journalsubtitle = {nova série},
+        volume          = {7},
+        part            = {2},
+        number          = {1},
+        issuetitle      = {Dossiê Nordeste},
+        issuesubtitle   = {sertões e fronteiras},
+        eid             = {e20190012},
+        date            = {2020-05},
+        entrysubtype    = {magazine}
+      }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "SOUZA, A. Sertões. <strong>Revista de História</strong>: nova série, Dossiê Nordeste: sertões e fronteiras, v. 7, pt. 2, n. 1, e20190012, maio 2020.",
+    );
 }
 
 #[test]
 fn book_from_bib3() {
     let entry = "
     @movie{TroubledLand1961,
-        title        = {{The troubled} land. Produção e direção de Helen Jean Rogers},
+        title        = {{The troubled} land},
+        director     = {Rogers, Helen Jean},
+        producer     = {Rogers, Helen Jean},
         sorttitle    = {Troubled land, The},
         location     = {Recife},
         publisher    = {ABC Studios},
@@ -236,13 +467,12 @@ fn book_from_bib3() {
 
     let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
 
-    for bib in bibtex.bibliographies() {
-        assert_eq!(
-            format!("{}", Abnt(bib)),
-            r#"THE TROUBLED land. Produção e direção de Helen Jean Rogers. Recife: ABC Studios, 1961."#,
-        );
-        break;
-    }
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        r#"THE TROUBLED land. Produção e direção: Helen Jean Rogers. Recife: ABC Studios, 1961."#,
+    );
 }
 
 #[test]
@@ -260,13 +490,12 @@ fn book_from_bib() {
 
     let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
 
-    for bib in bibtex.bibliographies() {
-        assert_eq!(
-            format!("{}", Abnt(bib)),
-            "ASSUNÇÃO, P. <strong>Negócios Jesuíticos</strong>: o cotidiano da administração dos bens divinos. São Paulo: Editora da Universidade de São Paulo, 2004.",
-        );
-        break;
-    }
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "ASSUNÇÃO, P. <strong>Negócios Jesuíticos</strong>: o cotidiano da administração dos bens divinos. São Paulo: Editora da Universidade de São Paulo, 2004.",
+    );
 }
 
 #[test]
@@ -280,13 +509,12 @@ fn book_from_bib2() {
 
     let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
 
-    for bib in bibtex.bibliographies() {
-        assert_eq!(
-            format!("{}", Abnt(bib)),
-            "PASSOS, A. B. [s.l.: s.n.], 1854.",
-        );
-        break;
-    }
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "PASSOS, A. B. [s.l.: s.n.], 1854.",
+    );
 }
 
 #[test]
@@ -304,13 +532,12 @@ fn thesis_from_bib() {
 
     let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
 
-    for bib in bibtex.bibliographies() {
-        assert_eq!(
-            format!("{}", Abnt(bib)),
-            "DIAS, T. A. <strong>Dinâmicas mercantis coloniais</strong>: capitania do Rio Grande do Norte (1760–1821). 2011. Dissertação (Mestrado em História e espaços) – Universidade Federal do Rio Grande do Norte.",
-        );
-        break;
-    }
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "DIAS, T. A. <strong>Dinâmicas mercantis coloniais</strong>: capitania do Rio Grande do Norte (1760–1821). 2011. Dissertação (Mestrado em História e espaços) – Universidade Federal do Rio Grande do Norte.",
+    );
 }
 
 #[test]
@@ -332,13 +559,12 @@ fn inbook_from_bib() {
 
     let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
 
-    for bib in bibtex.bibliographies() {
-        assert_eq!(
-            format!("{}", Abnt(bib)),
-            "FRAGOSO, J. A. A formação da economia colonial no Rio de Janeiro e de sua primeira elite senhorial (séculos XVI e XVII). In: FRAGOSO, J.; BICALHO, M. F.; GOUVÊA, M. F. <strong>O Antigo Regime nos trópicos</strong>: a dinâmica Imperial portuguesa (séculos XVI-XVIII). Rio de Janeiro: Civilização Brasileira, 2001.",
-        );
-        break;
-    }
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "FRAGOSO, J. A. A formação da economia colonial no Rio de Janeiro e de sua primeira elite senhorial (séculos XVI e XVII). In: FRAGOSO, J.; BICALHO, M. F.; GOUVÊA, M. F. <strong>O Antigo Regime nos trópicos</strong>: a dinâmica Imperial portuguesa (séculos XVI-XVIII). Rio de Janeiro: Civilização Brasileira, 2001.",
+    );
 }
 
 #[test]
@@ -356,13 +582,12 @@ fn article_from_bib_2() {
 
     let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
 
-    for bib in bibtex.bibliographies() {
-        assert_eq!(
-            format!("{}", Abnt(bib)),
-            "TRANSLADO do Auto de Terras do Rio Grande. <strong>Revista do IHGRN</strong>, v. 7, n. 1 e 2, p. 5–131, 1909.",
-        );
-        break;
-    }
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "TRANSLADO do Auto de Terras do Rio Grande. <strong>Revista do IHGRN</strong>, v. 7, n. 1 e 2, p. 5–131, 1909.",
+    );
 }
 
 #[test]
@@ -384,13 +609,12 @@ fn incollection_from_bib() {
 
     let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
 
-    for bib in bibtex.bibliographies() {
-        assert_eq!(
-            format!("{}", Abnt(bib)),
-            "ALVEAL, C. M. O. Uma análise preliminar das sesmarias nas Capitanias do Norte. In: SILVA, G. C. M. (Org.). <strong>A época moderna e o Brasil colonial</strong>: conceitos, fontes e pesquisas. Maceió: EDUFAL, 2019.",
-        );
-        break;
-    }
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "ALVEAL, C. M. O. Uma análise preliminar das sesmarias nas Capitanias do Norte. In: SILVA, G. C. M. (Org.). <strong>A época moderna e o Brasil colonial</strong>: conceitos, fontes e pesquisas. Maceió: EDUFAL, 2019.",
+    );
 }
 
 #[test]
@@ -412,13 +636,12 @@ fn inproceedings_from_bib() {
 
     let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
 
-    for bib in bibtex.bibliographies() {
-        assert_eq!(
-            format!("{}", Abnt(bib)),
-            "MOTTER, M. d. L. Telenovela: reflexo e refração na arte do cotidiano. In: CONGRESSO BRASILEIRO DE CIÊNCIAS DA COMUNICAÇÃO, 21, 1998, Recife.",
-        );
-        break;
-    }
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        r#"MOTTER, M. d. L. Telenovela: reflexo e refração na arte do cotidiano. In: CONGRESSO BRASILEIRO DE CIÊNCIAS DA COMUNICAÇÃO, 21., 1998, Recife. Disponível em: <span class="font-mono">&lt;<a href="http://www.portcom.intercom.org.br/pdfs/de14671ff94329deb4d1756ec2696184.PDF">http://www.portcom.intercom.org.br/pdfs/de14671ff94329deb4d1756ec2696184.PDF</a>&gt;</span>."#,
+    );
 }
 
 #[test]
@@ -436,11 +659,440 @@ fn collection_from_bib() {
 
     let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
 
-    for bib in bibtex.bibliographies() {
-        assert_eq!(
-            format!("{}", Abnt(bib)),
-            "LAPA, J. R. A. (Org.). <strong>Modos de produção e realidade brasileira</strong>. Petrópolis: Vozes, 1980.",
-        );
-        break;
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "LAPA, J. R. A. (Org.). <strong>Modos de produção e realidade brasileira</strong>. Petrópolis: Vozes, 1980.",
+    );
+}
+
+#[test]
+fn book_with_contributors() {
+    let entry = r"
+    @book{Boxer1969,
+        editor     = {Boxer, C. R.},
+        editortype = {coordinator},
+        title      = {O império marítimo português},
+        translator = {Costa, Anna Olga de Barros and Paulo Barros},
+        foreword   = {Cascudo, L. C.},
+        location   = {Lisboa},
+        publisher  = {Edições 70},
+        year       = {1969}
+      }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "BOXER, C. R. (Coord.). <strong>O império marítimo português</strong>. Tradução de Anna Olga de Barros Costa e Paulo Barros. Prefácio de L. C. Cascudo. Lisboa: Edições 70, 1969.",
+    );
+}
+
+#[test]
+fn mvbook_from_bib() {
+    let entry = r"
+    @mvbook{Cascudo1956,
+        author    = {Cascudo, L. C.},
+        title     = {História da cidade do Natal},
+        location  = {Natal},
+        publisher = {Prefeitura Municipal},
+        year      = {1956},
+        volumes   = {3},
+        series    = {Coleção Brasiliana},
+        number    = {12}
+      }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "CASCUDO, L. C. <strong>História da cidade do Natal</strong>. Natal: Prefeitura Municipal, 1956. 3 v. (Coleção Brasiliana, v. 12).",
+    );
+}
+
+#[test]
+fn localized_from_langid() {
+    let entry = r"
+    @incollection{Schwartz1985,
+        author     = {Schwartz, S. B.},
+        title      = {Colonial Brazil},
+        booktitle  = {The Cambridge History of Latin America},
+        editor     = {Bethell, L.},
+        editortype = {organizer},
+        year       = {1985},
+        url        = {https://doi.org/10.1017/CHOL9780521232234},
+        urldate    = {2020-05-14},
+        langid     = {english}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        r#"SCHWARTZ, S. B. Colonial Brazil. In: BETHELL, L. (Ed.). <strong>The Cambridge History of Latin America</strong>. [S.l.: s.n.], 1985. Available at: <span class="font-mono">&lt;<a href="https://doi.org/10.1017/CHOL9780521232234">https://doi.org/10.1017/CHOL9780521232234</a>&gt;</span>. Accessed on: 14 May 2020."#,
+    );
+}
+
+#[test]
+fn article_month_range() {
+    let entry = r"
+    @article{Veja2001,
+        author   = {Souza, A.},
+        title    = {O sertão},
+        journal  = {Veja},
+        location = {São Paulo},
+        number   = 12,
+        year     = 2001,
+        month    = {1/3}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "SOUZA, A. O sertão. <strong>Veja</strong>, São Paulo, n. 12, jan./mar. 2001.",
+    );
+}
+
+#[test]
+fn undated_book() {
+    let entry = r"
+    @book{Cascudo,
+        author    = {Cascudo, L. C.},
+        title     = {Vaqueiros e cantadores},
+        location  = {Natal},
+        publisher = {Fundação José Augusto},
+        year      = {197X?}
+    }
+    @book{CascudoSd,
+        author    = {Cascudo, L. C.},
+        title     = {Vaqueiros e cantadores},
+        location  = {Natal},
+        publisher = {Fundação José Augusto}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bibs = bibtex.bibliographies();
+
+    assert_eq!(
+        format!("{}", Abnt(&bibs[0])),
+        "CASCUDO, L. C. <strong>Vaqueiros e cantadores</strong>. Natal: Fundação José Augusto, [197-?].",
+    );
+    assert_eq!(
+        format!("{}", Abnt(&bibs[1])),
+        "CASCUDO, L. C. <strong>Vaqueiros e cantadores</strong>. Natal: Fundação José Augusto, [s.d.].",
+    );
+}
+
+#[test]
+fn reprint_from_bib() {
+    let entry = r"
+    @book{Salvador1627,
+        author        = {Salvador, V.},
+        title         = {História do Brasil},
+        location      = {Brasília},
+        publisher     = {Senado Federal},
+        year          = {2010},
+        origdate      = {1627},
+        origlocation  = {Bahia},
+        relatedtype   = {facsimile}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "SALVADOR, V. <strong>História do Brasil</strong>. Brasília: Senado Federal, 2010. Edição fac-similar. Publicado originalmente em Bahia, 1627.",
+    );
+}
+
+#[test]
+fn personal_from_bib() {
+    let entry = r"
+    @interview{Silva2015,
+        author       = {Silva, Maria},
+        interviewer  = {Souza, Ana},
+        location     = {Caicó},
+        eventdate    = {2015-03-12},
+        year         = {2016},
+        howpublished = {1 arquivo .mp3 (45 min)}
+    }
+
+    @misc{Mariz1930,
+        author       = {Mariz, Dinarte},
+        title        = {Ao povo potiguar},
+        entrysubtype = {speech},
+        eventtitle   = {Assembleia Legislativa},
+        location     = {Natal},
+        date         = {1930-10-03}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bibs = bibtex.bibliographies();
+
+    assert_eq!(
+        format!("{}", Abnt(&bibs[0])),
+        "SILVA, M. Entrevista concedida a Ana Souza. Caicó, 12 mar. 2015. 1 arquivo .mp3 (45 min).",
+    );
+    assert_eq!(
+        format!("{}", Abnt(&bibs[1])),
+        "MARIZ, D. <strong>Ao povo potiguar</strong>. Discurso proferido em Assembleia Legislativa. Natal, 3 out. 1930.",
+    );
+}
+
+#[test]
+fn proceedings_from_bib() {
+    let entry = r"
+    @proceedings{EEH2016,
+        eventtitle = {Encontro Estadual de História},
+        number     = {17},
+        venue      = {Guarabira},
+        title      = {Anais},
+        editor     = {Dias, Patrícia de Oliveira},
+        editortype = {organizer},
+        location   = {Guarabira},
+        publisher  = {ANPUH-PB},
+        year       = {2016}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "ENCONTRO ESTADUAL DE HISTÓRIA, 17., 2016, Guarabira. <strong>Anais</strong> [...]. Organização de Patrícia de Oliveira Dias. Guarabira: ANPUH-PB, 2016.",
+    );
+}
+
+#[test]
+fn proceedings_without_event() {
+    let entry = r"
+    @proceedings{Semana2018,
+        title     = {{{Semana de História} do Seridó}},
+        subtitle  = {anais},
+        location  = {Caicó},
+        publisher = {UFRN},
+        year      = {2018}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "SEMANA DE HISTÓRIA do Seridó: anais. Caicó: UFRN, 2018.",
+    );
+}
+
+#[test]
+fn event_date_from_bib() {
+    let entry = r"
+    @inproceedings{Medeiros2019,
+        author     = {Medeiros, Ana},
+        title      = {A pecuária no Seridó},
+        eventtitle = {Simpósio Nacional de História},
+        number     = {30},
+        eventdate  = {2019-07-15/2019-07-19},
+        venue      = {Recife},
+        booktitle  = {Anais},
+        location   = {São Paulo},
+        publisher  = {ANPUH},
+        year       = {2020}
     }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "MEDEIROS, A. A pecuária no Seridó. In: SIMPÓSIO NACIONAL DE HISTÓRIA, 30., 2019, Recife. <strong>Anais</strong> [...]. São Paulo: ANPUH, 2020.",
+    );
+}
+
+#[test]
+fn periodical_from_bib() {
+    let entry = r"
+    @periodical{RIHGRN1909,
+        title     = {Revista do IHGRN},
+        location  = {Natal},
+        publisher = {IHGRN},
+        volume    = {7},
+        number    = {1 e 2},
+        year      = {1909},
+        issn      = {0103-6505}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        "REVISTA DO IHGRN. Natal: IHGRN, v. 7, n. 1 e 2, 1909. ISSN 0103-6505.",
+    );
+}
+
+#[test]
+fn inreference_from_bib() {
+    let entry = r"
+    @inreference{Mariz2009,
+        title       = {Dinarte de Medeiros Mariz},
+        booktitle   = {Dicionário histórico-biográfico brasileiro},
+        editor      = {Abreu, A. A.},
+        editortype  = {coordinator},
+        edition     = {2},
+        location    = {Rio de Janeiro},
+        publisher   = {FGV},
+        year        = {2001},
+        url         = {https://cpdoc.fgv.br/verbete/dinarte-mariz},
+        urldate     = {2020-05-14}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Abnt(bib)),
+        r#"DINARTE de Medeiros Mariz. In: ABREU, A. A. (Coord.). <strong>Dicionário histórico-biográfico brasileiro</strong>. 2. ed. Rio de Janeiro: FGV, 2001. Disponível em: <span class="font-mono">&lt;<a href="https://cpdoc.fgv.br/verbete/dinarte-mariz">https://cpdoc.fgv.br/verbete/dinarte-mariz</a>&gt;</span>. Acesso em: 14 maio 2020."#,
+    );
+}
+
+#[test]
+fn authored_and_edited_book() {
+    let entries = r"
+    @book{Cascudo1984,
+        editor     = {Lima, Diógenes da Cunha},
+        editortype = {organizer},
+        author     = {Cascudo, L. C.},
+        title      = {História do Rio Grande do Norte},
+        location   = {Natal},
+        publisher  = {Fundação José Augusto},
+        year       = {1984}
+    }
+    @book{Cascudo1968,
+        editor     = {Lima, Diógenes da Cunha},
+        editortype = {compiler},
+        author     = {Cascudo, L. C.},
+        title      = {Prelúdio da cachaça},
+        location   = {Natal},
+        publisher  = {Fundação José Augusto},
+        year       = {1968}
+    }
+    @book{Lapa1980,
+        editor    = {Lapa, J. R. A.},
+        title     = {Modos de produção e realidade brasileira},
+        location  = {Petrópolis},
+        publisher = {Vozes},
+        year      = {1980}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entries).expect("valid bibtex");
+
+    let bibs = bibtex.bibliographies();
+
+    assert_eq!(
+        format!("{}", Abnt(&bibs[0])),
+        "CASCUDO, L. C. <strong>História do Rio Grande do Norte</strong>. Organização de Diógenes da Cunha Lima. Natal: Fundação José Augusto, 1984.",
+    );
+    assert_eq!(
+        format!("{}", Abnt(&bibs[1])),
+        "CASCUDO, L. C. <strong>Prelúdio da cachaça</strong>. Compilação de Diógenes da Cunha Lima. Natal: Fundação José Augusto, 1968.",
+    );
+    assert_eq!(
+        format!("{}", Abnt(&bibs[2])),
+        "LAPA, J. R. A. (Ed.). <strong>Modos de produção e realidade brasileira</strong>. Petrópolis: Vozes, 1980.",
+    );
+}
+
+#[test]
+fn sort_by_entry_element() {
+    let entries = r"
+    @book{Abreu, author = {Abreu, A. A.}, title = {Verbetes}, year = {2001}}
+    @inreference{Mariz,
+        title     = {{Dinarte de Medeiros Mariz}},
+        editor    = {Silva, A. A.},
+        booktitle = {Dicionário histórico-biográfico brasileiro},
+        year      = {2001}
+    }
+    @book{Cascudo, author = {Cascudo, L. C.}, title = {Vaqueiros}, year = {1939}}
+    @book{Duarte, author = {Duarte, T. A.}, title = {Sertões}, year = {2011}}
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entries).expect("valid bibtex");
+
+    let mut bibs: Vec<_> = bibtex.bibliographies().iter().map(Abnt).collect();
+    bibs.sort();
+
+    let keys: Vec<_> = bibs.iter().map(|b| b.0.citation_key()).collect();
+
+    assert_eq!(keys, ["Abreu", "Cascudo", "Mariz", "Duarte"]);
+}
+
+#[test]
+fn sort_song_by_composer() {
+    let entries = r"
+    @audio{Souza, author = {Souza, A.}, title = {Sertão}, year = {1990}}
+    @music{Valsa, composer = {Buarque, C.}, title = {Valsa}, year = {1984}}
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entries).expect("valid bibtex");
+
+    let mut bibs: Vec<_> = bibtex.bibliographies().iter().map(Abnt).collect();
+    bibs.sort();
+
+    let keys: Vec<_> = bibs.iter().map(|b| b.0.citation_key()).collect();
+
+    assert_eq!(keys, ["Valsa", "Souza"]);
+}
+
+#[test]
+fn sort_proceedings_by_event() {
+    let entries = r"
+    @book{Borges, author = {Borges, J.}, title = {Ficções}, year = {1944}}
+    @proceedings{EEH2016,
+        eventtitle = {Encontro Estadual de História},
+        title      = {Anais},
+        year       = {2016}
+    }
+    @book{Dias, author = {Dias, T. A.}, title = {Sertões}, year = {2011}}
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entries).expect("valid bibtex");
+
+    let mut bibs: Vec<_> = bibtex.bibliographies().iter().map(Abnt).collect();
+    bibs.sort();
+
+    let keys: Vec<_> = bibs.iter().map(|b| b.0.citation_key()).collect();
+
+    assert_eq!(keys, ["Borges", "Dias", "EEH2016"]);
 }
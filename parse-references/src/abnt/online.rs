@@ -0,0 +1,163 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use super::{
+    authors::Authors, date::Date, join::Join, locale, location_publisher::LocationPublisher,
+    strong::Strong, tex, uppercase::title_entry,
+};
+
+/// A document available only online, such as a page of a website. Its URL
+/// and access date are written by `ExtraInfo`.
+pub struct Online<'o> {
+    author: &'o str,
+    title: &'o str,
+    subtitle: Option<&'o str>,
+    /// Name of the website the page belongs to.
+    maintitle: Option<&'o str>,
+    /// Whoever maintains the website, the `organization` or `publisher`.
+    owner: Option<&'o str>,
+    location: Option<&'o str>,
+    date: Date<'o>,
+}
+
+impl<'o> Online<'o> {
+    pub fn from_bib(b: &'o Bibliography) -> Self {
+        let mut online = Online {
+            author: "",
+            title: "",
+            subtitle: None,
+            maintitle: None,
+            owner: None,
+            location: None,
+            date: Date::from_bib(b),
+        };
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "author" => online.author = v.trim(),
+                "title" => online.title = v.trim(),
+                "subtitle" => online.subtitle = Some(v.trim()),
+                "maintitle" => online.maintitle = Some(v.trim()),
+                "organization" => online.owner = Some(v.trim()),
+                "publisher" if online.owner.is_none() => online.owner = Some(v.trim()),
+                "location" | "address" => online.location = Some(v.trim()),
+                _ => continue,
+            }
+        }
+
+        online
+    }
+}
+
+impl<'o> Display for Online<'o> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let title = [Some(self.title).filter(|t| !t.is_empty()), self.subtitle]
+            .into_iter()
+            .flatten()
+            .map(tex::Text);
+        let title = format!("{}", Join::new(": ", title));
+
+        if self.author.is_empty() {
+            // The title is the entry element, its first word in uppercase.
+            match (self.title, self.subtitle) {
+                ("", Some(subtitle)) => title_entry(f, subtitle)?,
+                (title, subtitle) => {
+                    title_entry(f, title)?;
+
+                    if let Some(subtitle) = subtitle {
+                        write!(f, ": {}", tex::Text(subtitle))?;
+                    }
+                }
+            }
+
+            f.write_str(".")?;
+        } else {
+            let mut authors = format!("{}", Authors(self.author));
+            if authors.ends_with('.') {
+                authors.pop();
+            }
+
+            write!(f, "{}. {}.", authors, title)?;
+        }
+
+        if let Some(maintitle) = self.maintitle {
+            write!(
+                f,
+                " {}: {}.",
+                locale::terms().in_,
+                Strong(tex::Text(maintitle))
+            )?;
+        }
+
+        let date = Some(&self.date).filter(|d| !d.is_empty());
+
+        if self.location.is_some() || self.owner.is_some() {
+            let loc_pub = LocationPublisher(self.location.unwrap_or(""), self.owner.unwrap_or(""));
+
+            match date {
+                Some(date) => write!(f, " {}, {}.", loc_pub, date)?,
+                None => write!(f, " {}.", loc_pub)?,
+            }
+        } else if let Some(date) = date {
+            write!(f, " {}.", date)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn website_page() {
+    let online = Online {
+        author: "",
+        title: "Dinarte de Medeiros Mariz",
+        subtitle: None,
+        maintitle: Some("CPDOC"),
+        owner: Some("Fundação Getulio Vargas"),
+        location: Some("Rio de Janeiro"),
+        date: Date::parse("2009-05"),
+    };
+
+    assert_eq!(
+        format!("{online}"),
+        "DINARTE de Medeiros Mariz. In: <strong>CPDOC</strong>. Rio de Janeiro: Fundação Getulio Vargas, maio 2009.",
+    );
+}
+
+#[test]
+fn signed_page() {
+    let online = Online {
+        author: "Dias, T. A.",
+        title: "Sertões",
+        subtitle: None,
+        maintitle: None,
+        owner: None,
+        location: None,
+        date: Date::from_year(""),
+    };
+
+    assert_eq!(format!("{online}"), "DIAS, T. A. Sertões.");
+}
+
+#[test]
+fn braced_title() {
+    let online = |title| Online {
+        author: "",
+        title,
+        subtitle: None,
+        maintitle: None,
+        owner: None,
+        location: None,
+        date: Date::from_year("2020"),
+    };
+
+    assert_eq!(
+        format!("{}", online("{{The troubled} land}")),
+        "THE TROUBLED land. 2020.",
+    );
+    assert_eq!(
+        format!("{}", online("{\\emph{Dom Casmurro} online}")),
+        "DOM CASMURRO online. 2020.",
+    );
+}
@@ -0,0 +1,116 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use super::{date::Date, locale, tex};
+
+/// Notes about the original publication of a reprint or facsimile:
+/// `Edição fac-similar. Publicado originalmente em Lisboa: Craesbeeck, 1627.`
+pub struct Original<'o> {
+    date: Option<Date<'o>>,
+    location: Option<&'o str>,
+    publisher: Option<&'o str>,
+    related_type: Option<&'o str>,
+    related_string: Option<&'o str>,
+}
+
+impl<'o> Original<'o> {
+    pub fn from_bib(b: &'o Bibliography) -> Self {
+        let mut original = Original {
+            date: None,
+            location: None,
+            publisher: None,
+            related_type: None,
+            related_string: None,
+        };
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "origdate" => original.date = Some(Date::parse(v)),
+                "origyear" if original.date.is_none() => original.date = Some(Date::parse(v)),
+                "origlocation" => original.location = Some(v.trim()),
+                "origpublisher" => original.publisher = Some(v.trim()),
+                "relatedtype" => original.related_type = Some(v.trim()),
+                "relatedstring" => original.related_string = Some(v.trim()),
+                _ => continue,
+            }
+        }
+
+        original
+    }
+}
+
+impl<'o> Display for Original<'o> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let terms = locale::terms();
+
+        let related = self.related_string.map(tex::Text);
+        let related_term = match self.related_type {
+            Some("facsimile") => Some(terms.facsimile),
+            Some("reprint" | "reprintof" | "reprintfrom") => Some(terms.reprint),
+            _ => None,
+        };
+
+        if let Some(related) = related {
+            write!(f, " {}.", related)?;
+        } else if let Some(term) = related_term {
+            write!(f, " {}.", term)?;
+        }
+
+        if self.date.is_none() && self.location.is_none() && self.publisher.is_none() {
+            return Ok(());
+        }
+
+        write!(f, " {} ", terms.originally_published)?;
+
+        match (self.location, self.publisher) {
+            (Some(location), Some(publisher)) => {
+                write!(f, "{}: {}", tex::Text(location), tex::Text(publisher))?
+            }
+            (Some(place), None) | (None, Some(place)) => write!(f, "{}", tex::Text(place))?,
+            (None, None) => {}
+        }
+
+        if let Some(date) = self.date {
+            if self.location.is_some() || self.publisher.is_some() {
+                f.write_str(", ")?;
+            }
+
+            write!(f, "{}", date)?;
+        }
+
+        f.write_str(".")
+    }
+}
+
+#[test]
+fn facsimile() {
+    let original = Original {
+        date: Some(Date::from_year("1627")),
+        location: Some("Lisboa"),
+        publisher: Some("Craesbeeck"),
+        related_type: Some("facsimile"),
+        related_string: None,
+    };
+
+    assert_eq!(
+        format!("{original}"),
+        " Edição fac-similar. Publicado originalmente em Lisboa: Craesbeeck, 1627.",
+    );
+}
+
+#[test]
+fn original_date() {
+    let original = Original {
+        date: Some(Date::parse("1627?")),
+        location: None,
+        publisher: None,
+        related_type: None,
+        related_string: None,
+    };
+
+    assert_eq!(
+        format!("{original}"),
+        " Publicado originalmente em [1627?]."
+    );
+}
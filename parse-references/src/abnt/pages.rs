@@ -1,30 +1,112 @@
 use std::fmt::Display;
 
+use super::join::Join;
+
 pub enum Pages<'p> {
     Single(&'p str),
     Range(&'p str, &'p str),
+    /// Pages and ranges separated by commas: `3, 7, 9–12`.
+    List(Vec<Pages<'p>>),
 }
 
-impl<'p> Display for Pages<'p> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// What the numbers of `Pages` count, after the biblatex `pagination` field.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Pagination {
+    #[default]
+    Page,
+    /// Leaves of a manuscript, numbered on the recto and verso: `f. 12v–13r`.
+    Folio,
+    Column,
+}
+
+impl Pagination {
+    pub fn from_field(s: &str) -> Option<Self> {
+        match s.trim() {
+            "page" => Some(Pagination::Page),
+            "folio" => Some(Pagination::Folio),
+            "column" => Some(Pagination::Column),
+            _ => None,
+        }
+    }
+
+    /// The pagination named by the label that starts `s`, if any, and the
+    /// rest of `s`: `f. 12v-13r`.
+    pub fn from_label(s: &str) -> Option<(Self, &str)> {
+        const LABELS: [(&str, Pagination); 8] = [
+            ("pp.", Pagination::Page),
+            ("p.", Pagination::Page),
+            ("ff.", Pagination::Folio),
+            ("fls.", Pagination::Folio),
+            ("fl.", Pagination::Folio),
+            ("f.", Pagination::Folio),
+            ("cols.", Pagination::Column),
+            ("col.", Pagination::Column),
+        ];
+
+        let s = s.trim();
+
+        LABELS
+            .iter()
+            .find_map(|(label, p)| s.strip_prefix(label).map(|rest| (*p, rest.trim_start())))
+    }
+
+    pub fn abbreviation(self) -> &'static str {
         match self {
-            Pages::Single(page) => write!(f, "p. {page}"),
-            Pages::Range(first, last) => write!(f, "p. {first}–{last}"),
+            Pagination::Page => "p.",
+            Pagination::Folio => "f.",
+            Pagination::Column => "col.",
         }
     }
 }
 
+/// Pages with the label of their pagination: `f. 12v–13r`.
+pub struct Labeled<'p>(pub &'p Pages<'p>, pub Pagination);
+
+/// The numbers of pages without label, ranges joined by an en dash.
+struct Numbers<'p>(&'p Pages<'p>);
+
+impl<'p> Display for Numbers<'p> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Pages::Single(page) => write!(f, "{page}"),
+            Pages::Range(first, last) => write!(f, "{first}–{last}"),
+            Pages::List(pages) => write!(f, "{}", Join::new(", ", pages.iter().map(Numbers))),
+        }
+    }
+}
+
+impl<'p> Display for Labeled<'p> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.1.abbreviation(), Numbers(self.0))
+    }
+}
+
+impl<'p> Display for Pages<'p> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Labeled(self, Pagination::Page))
+    }
+}
+
 impl<'p> Pages<'p> {
+    /// Accepts ranges separated by `--`, an en or em dash or a hyphen, lists
+    /// separated by commas and a leading label such as `p.` or `f.`, which
+    /// `Pagination::from_label` recognizes.
     pub fn from_str(s: &'p str) -> Self {
-        let mut parts = s.split("--");
-        
-        let first = parts.next().unwrap_or("");
-        let last = parts.next();
-
-        if let Some(last) = last {
-            Self::Range(first, last)
-        } else {
-            Self::Single(first)
+        let s = Pagination::from_label(s).map_or(s, |(_, rest)| rest).trim();
+
+        if s.contains(',') {
+            return Self::List(s.split(',').map(|p| Self::from_str(p.trim())).collect());
+        }
+
+        let range = ["--", "–", "—", "-"]
+            .iter()
+            .find_map(|separator| s.split_once(separator));
+
+        match range {
+            Some((first, last)) if !first.trim().is_empty() && !last.trim().is_empty() => {
+                Self::Range(first.trim(), last.trim())
+            }
+            _ => Self::Single(s),
         }
     }
 }
@@ -33,7 +115,7 @@ impl<'p> Pages<'p> {
 fn single_page() {
     let p = Pages::Single("v");
     let output = format!("{p}");
-    
+
     assert_eq!(output, "p. v");
 }
 
@@ -41,6 +123,35 @@ fn single_page() {
 fn page_range() {
     let p = Pages::Range("2", "10");
     let output = format!("{p}");
-    
+
     assert_eq!(output, "p. 2–10");
 }
+
+#[test]
+fn separators() {
+    for s in ["2--10", "2–10", "2-10", "2 - 10"] {
+        assert_eq!(format!("{}", Pages::from_str(s)), "p. 2–10");
+    }
+
+    assert_eq!(format!("{}", Pages::from_str("xi-xx")), "p. xi–xx");
+    assert_eq!(
+        format!("{}", Pages::from_str("3, 7, 9-12")),
+        "p. 3, 7, 9–12"
+    );
+}
+
+#[test]
+fn folios() {
+    let s = "f. 12v-13r";
+    let pagination = Pagination::from_label(s).map(|(p, _)| p);
+
+    assert_eq!(pagination, Some(Pagination::Folio));
+    assert_eq!(
+        format!("{}", Labeled(&Pages::from_str(s), Pagination::Folio)),
+        "f. 12v–13r"
+    );
+    assert_eq!(
+        format!("{}", Labeled(&Pages::Single("3"), Pagination::Column)),
+        "col. 3"
+    );
+}
@@ -0,0 +1,175 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use super::{
+    date::Date, description::Description, issue::Issue, join::Join,
+    location_publisher::LocationPublisher, pages::Pagination, tex, uppercase::Uppercase,
+    volume::Volume,
+};
+
+/// A whole periodical, or one of its issues, entered by its title in
+/// uppercase: `REVISTA DO IHGRN. Natal: IHGRN, v. 7, n. 1 e 2, 1909.`
+pub struct Periodical<'p> {
+    title: &'p str,
+    subtitle: Option<&'p str>,
+    /// Title of a special issue or dossier.
+    issue_title: Option<&'p str>,
+    issue_subtitle: Option<&'p str>,
+    location: Option<&'p str>,
+    publisher: Option<&'p str>,
+    volume: Option<&'p str>,
+    issue: Option<&'p str>,
+    date: Date<'p>,
+    /// The number of pages of an issue.
+    description: Description<'p>,
+}
+
+impl<'p> Periodical<'p> {
+    pub fn from_bib(b: &'p Bibliography) -> Self {
+        let mut periodical = Periodical {
+            title: "",
+            subtitle: None,
+            issue_title: None,
+            issue_subtitle: None,
+            location: None,
+            publisher: None,
+            volume: None,
+            issue: None,
+            date: Date::from_bib(b),
+            description: Description::default(),
+        };
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "title" | "journal" | "journaltitle" => periodical.title = v.trim(),
+                "subtitle" => periodical.subtitle = Some(v.trim()),
+                "issuetitle" => periodical.issue_title = Some(v.trim()),
+                "issuesubtitle" => periodical.issue_subtitle = Some(v.trim()),
+                "location" | "address" => periodical.location = Some(v.trim()),
+                "publisher" | "organization" => periodical.publisher = Some(v.trim()),
+                "volume" => periodical.volume = Some(v.trim()),
+                "issue" | "number" => periodical.issue = Some(v.trim()),
+                "pagetotal" => periodical.description.pagetotal = Some(v.trim()),
+                "bookpagination" => {
+                    periodical.description.pagination =
+                        Pagination::from_field(v.trim()).unwrap_or_default()
+                }
+                _ => continue,
+            }
+        }
+
+        periodical
+    }
+}
+
+impl<'p> Display for Periodical<'p> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Uppercase(&tex::Text(self.title).to_string()))?;
+
+        if let Some(subtitle) = self.subtitle {
+            write!(f, ": {}", tex::Text(subtitle))?;
+        }
+
+        f.write_str(".")?;
+
+        if let Some(issue_title) = self.issue_title {
+            write!(f, " {}", tex::Text(issue_title))?;
+
+            if let Some(subtitle) = self.issue_subtitle {
+                write!(f, ": {}", tex::Text(subtitle))?;
+            }
+
+            f.write_str(".")?;
+        }
+
+        let loc_pub = match (self.location, self.publisher) {
+            (location, Some(publisher)) => {
+                Some(LocationPublisher(location.unwrap_or(""), publisher).to_string())
+            }
+            (Some(location), None) => Some(tex::Text(location).to_string()),
+            (None, None) => None,
+        };
+
+        let parts = [
+            loc_pub,
+            self.volume.map(|v| Volume(v).to_string()),
+            self.issue.map(|i| Issue(i).to_string()),
+            Some(self.date.to_string()),
+        ];
+
+        write!(f, " {}.", Join::new(", ", parts.into_iter().flatten()))?;
+
+        write!(f, "{}", self.description)
+    }
+}
+
+#[test]
+fn issue_of_journal() {
+    let periodical = Periodical {
+        title: "Revista do IHGRN",
+        subtitle: None,
+        issue_title: None,
+        issue_subtitle: None,
+        location: Some("Natal"),
+        publisher: Some("IHGRN"),
+        volume: Some("7"),
+        issue: Some("1 e 2"),
+        date: Date::from_year("1909"),
+        description: Description::default(),
+    };
+
+    assert_eq!(
+        format!("{periodical}"),
+        "REVISTA DO IHGRN. Natal: IHGRN, v. 7, n. 1 e 2, 1909.",
+    );
+}
+
+#[test]
+fn dossier() {
+    let periodical = Periodical {
+        title: "Dinheiro",
+        subtitle: Some("revista semanal de negócios"),
+        issue_title: Some("Dossiê Nordeste"),
+        issue_subtitle: Some("sertões e fronteiras"),
+        location: Some("São Paulo"),
+        publisher: Some("Três Editorial"),
+        volume: None,
+        issue: Some("148"),
+        date: Date::parse("2000-06-28"),
+        description: Description {
+            pagetotal: Some("98"),
+            ..Default::default()
+        },
+    };
+
+    assert_eq!(
+        format!("{periodical}"),
+        "DINHEIRO: revista semanal de negócios. Dossiê Nordeste: sertões e fronteiras. São Paulo: Três Editorial, n. 148, 28 jun. 2000. 98 p.",
+    );
+}
+
+#[test]
+fn tex_title() {
+    let periodical = Periodical {
+        title: "Revista de Hist{\\'o}ria",
+        subtitle: None,
+        issue_title: None,
+        issue_subtitle: None,
+        location: Some("São Paulo"),
+        publisher: Some("USP"),
+        volume: None,
+        issue: Some("1"),
+        date: Date::from_year("1950"),
+        description: Description {
+            pagetotal: Some("120"),
+            pagination: Pagination::Folio,
+            ..Default::default()
+        },
+    };
+
+    assert_eq!(
+        format!("{periodical}"),
+        "REVISTA DE HISTÓRIA. São Paulo: USP, n. 1, 1950. 120 f.",
+    );
+}
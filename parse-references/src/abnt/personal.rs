@@ -0,0 +1,227 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use super::{
+    authors::Authors, date::Date, locale, roles::DirectOrder, strong::Strong, tex,
+    uppercase::title_entry,
+};
+
+/// Interviews, letters and speeches, which are entered by the person who
+/// gave, wrote or delivered them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PersonalKind {
+    Interview,
+    Letter,
+    Speech,
+}
+
+impl PersonalKind {
+    /// The kind of an `@interview`, `@letter` or `@speech`, or of a `@misc`
+    /// with one of those as its `entrysubtype`.
+    pub fn from_bib(b: &Bibliography) -> Option<Self> {
+        let kind = match b.entry_type() {
+            "misc" => b
+                .tags()
+                .iter()
+                .find(|(k, _)| k == "entrysubtype")
+                .map(|(_, v)| v.trim())?,
+            kind => kind,
+        };
+
+        match kind {
+            "interview" => Some(PersonalKind::Interview),
+            "letter" => Some(PersonalKind::Letter),
+            "speech" => Some(PersonalKind::Speech),
+            _ => None,
+        }
+    }
+}
+
+/// `SILVA, M. <strong>Memórias do Seridó</strong>. Entrevista concedida a
+/// Ana Souza. Caicó, 12 mar. 2015. 1 arquivo .mp3 (45 min).`
+pub struct Personal<'p> {
+    kind: PersonalKind,
+    author: &'p str,
+    title: Option<&'p str>,
+    interviewer: Option<&'p str>,
+    recipient: Option<&'p str>,
+    /// Event at which a speech was delivered.
+    event: Option<&'p str>,
+    location: Option<&'p str>,
+    /// The `eventdate` when the interview, letter or speech took place, or
+    /// else the `date`.
+    date: Date<'p>,
+    /// Physical description, the `howpublished`.
+    medium: Option<&'p str>,
+}
+
+impl<'p> Personal<'p> {
+    pub fn from_bib(kind: PersonalKind, b: &'p Bibliography) -> Self {
+        let mut personal = Personal {
+            kind,
+            author: "",
+            title: None,
+            interviewer: None,
+            recipient: None,
+            event: None,
+            location: None,
+            date: Date::from_bib(b),
+            medium: None,
+        };
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "author" => personal.author = v.trim(),
+                "title" => personal.title = Some(v.trim()),
+                "interviewer" => personal.interviewer = Some(v.trim()),
+                "recipient" => personal.recipient = Some(v.trim()),
+                "eventtitle" => personal.event = Some(v.trim()),
+                "location" | "address" | "venue" if personal.location.is_none() => {
+                    personal.location = Some(v.trim())
+                }
+                "eventdate" => personal.date = Date::parse(v.trim()),
+                "howpublished" => personal.medium = Some(v.trim()),
+                _ => continue,
+            }
+        }
+
+        personal
+    }
+}
+
+impl<'p> Display for Personal<'p> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let terms = locale::terms();
+
+        // Without an author, the title is the entry element. A letter
+        // without a title is described between brackets.
+        if self.author.is_empty() {
+            match (self.title, self.kind) {
+                (Some(title), _) => {
+                    title_entry(f, title)?;
+                    f.write_str(".")?;
+                }
+                (None, PersonalKind::Letter) => write!(f, "[{}].", terms.letter)?,
+                (None, _) => {}
+            }
+        } else {
+            let mut authors = format!("{}", Authors(self.author));
+            if authors.ends_with('.') {
+                authors.pop();
+            }
+
+            write!(f, "{}.", authors)?;
+
+            match (self.title, self.kind) {
+                (Some(title), _) => write!(f, " {}.", Strong(tex::Text(title)))?,
+                (None, PersonalKind::Letter) => write!(f, " [{}].", terms.letter)?,
+                (None, _) => {}
+            }
+        }
+
+        match self.kind {
+            PersonalKind::Interview => {
+                if let Some(interviewer) = self.interviewer {
+                    write!(
+                        f,
+                        " {} {}.",
+                        terms.interview_given_to,
+                        DirectOrder(interviewer)
+                    )?;
+                }
+            }
+            PersonalKind::Letter => {
+                if let Some(recipient) = self.recipient {
+                    write!(f, " {}: {}.", terms.recipient, DirectOrder(recipient))?;
+                }
+            }
+            PersonalKind::Speech => match self.event {
+                Some(event) => write!(
+                    f,
+                    " {} {} {}.",
+                    terms.speech,
+                    terms.delivered_at,
+                    tex::Text(event)
+                )?,
+                None => write!(f, " {}.", terms.speech)?,
+            },
+        }
+
+        match self.location {
+            Some(location) => write!(f, " {}, {}.", tex::Text(location), self.date)?,
+            None => write!(f, " {}.", self.date)?,
+        }
+
+        let medium = self.medium.or(match self.kind {
+            PersonalKind::Letter => Some(terms.one_letter),
+            _ => None,
+        });
+
+        if let Some(medium) = medium {
+            write!(f, " {}.", tex::Text(medium))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn interview() {
+    let interview = Personal {
+        kind: PersonalKind::Interview,
+        author: "Silva, Maria",
+        title: Some("Memórias do Seridó"),
+        interviewer: Some("Souza, Ana"),
+        recipient: None,
+        event: None,
+        location: Some("Caicó"),
+        date: Date::parse("2015-03-12"),
+        medium: Some("1 arquivo .mp3 (45 min)"),
+    };
+
+    assert_eq!(
+        format!("{interview}"),
+        "SILVA, M. <strong>Memórias do Seridó</strong>. Entrevista concedida a Ana Souza. Caicó, 12 mar. 2015. 1 arquivo .mp3 (45 min).",
+    );
+}
+
+#[test]
+fn untitled_letter() {
+    let letter = Personal {
+        kind: PersonalKind::Letter,
+        author: "Pilla, Luiz",
+        title: None,
+        interviewer: None,
+        recipient: Some("Vellinho, Moysés"),
+        event: None,
+        location: Some("Porto Alegre"),
+        date: Date::parse("1979-06-06"),
+        medium: None,
+    };
+
+    assert_eq!(
+        format!("{letter}"),
+        "PILLA, L. [Carta]. Destinatário: Moysés Vellinho. Porto Alegre, 6 jun. 1979. 1 carta.",
+    );
+}
+
+#[test]
+fn anonymous_interview() {
+    let interview = Personal {
+        kind: PersonalKind::Interview,
+        author: "",
+        title: Some("Memórias do Seridó"),
+        interviewer: Some("Souza, Ana"),
+        recipient: None,
+        event: None,
+        location: Some("Caicó"),
+        date: Date::parse("2015"),
+        medium: None,
+    };
+
+    assert_eq!(
+        format!("{interview}"),
+        "MEMÓRIAS do Seridó. Entrevista concedida a Ana Souza. Caicó, 2015.",
+    );
+}
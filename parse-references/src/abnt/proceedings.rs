@@ -0,0 +1,108 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use super::{
+    date::Date,
+    event::Event,
+    location_publisher::LocationPublisher,
+    roles::{DirectOrder, EditorType},
+    strong::Strong,
+    tex,
+    uppercase::title_entry,
+};
+
+/// The proceedings of an event, entered by the event:
+/// `ENCONTRO ESTADUAL DE HISTÓRIA, 17., 2016, Guarabira. <strong>Anais</strong>
+/// [...]. Guarabira: ANPUH-PB, 2016.`
+pub struct Proceedings<'p> {
+    event: Event<'p>,
+    /// The `title` of a `@proceedings`, or the `booktitle` of the papers in
+    /// it.
+    title: Option<&'p str>,
+    subtitle: Option<&'p str>,
+    editor: Option<&'p str>,
+    editor_type: EditorType,
+    location: &'p str,
+    publisher: Option<&'p str>,
+    date: Date<'p>,
+}
+
+impl<'p> Proceedings<'p> {
+    pub fn from_bib(b: &'p Bibliography) -> Self {
+        let whole = b.entry_type() == "proceedings";
+
+        let mut proceedings = Proceedings {
+            event: Event::from_bib(b),
+            title: None,
+            subtitle: None,
+            editor: None,
+            editor_type: EditorType::from_bib(b),
+            location: "",
+            publisher: None,
+            date: Date::from_bib(b),
+        };
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "title" if whole => proceedings.title = Some(v.trim()),
+                "subtitle" if whole => proceedings.subtitle = Some(v.trim()),
+                "booktitle" if !whole => proceedings.title = Some(v.trim()),
+                "booksubtitle" if !whole => proceedings.subtitle = Some(v.trim()),
+                "editor" | "organizer" => proceedings.editor = Some(v.trim()),
+                "location" | "address" => proceedings.location = v.trim(),
+                "publisher" | "organization" => proceedings.publisher = Some(v.trim()),
+                _ => continue,
+            }
+        }
+
+        // Proceedings without a publication date are dated by the event.
+        if proceedings.date.is_empty() {
+            proceedings.date = proceedings.event.date();
+        }
+
+        proceedings
+    }
+}
+
+impl<'p> Display for Proceedings<'p> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let subtitle = self.subtitle.map(|s| format!(": {}", tex::Text(s)));
+        let subtitle = subtitle.as_deref().unwrap_or("");
+
+        match (self.event.is_empty(), self.title) {
+            (false, Some(title)) => write!(
+                f,
+                "{}. {} [...].",
+                self.event,
+                Strong(format!("{}{}", tex::Text(title), subtitle))
+            )?,
+            (false, None) => write!(f, "{}.", self.event)?,
+            // Without an event, the title is the entry element.
+            (true, Some(title)) => {
+                title_entry(f, title)?;
+                write!(f, "{}.", subtitle)?;
+            }
+            (true, None) => {}
+        }
+
+        if let Some(editor) = self.editor {
+            write!(
+                f,
+                " {} {}.",
+                self.editor_type.contribution(),
+                DirectOrder(editor)
+            )?;
+        }
+
+        // Papers read at an event whose proceedings were not published only
+        // name the event.
+        if self.title.is_some() || self.publisher.is_some() {
+            let loc_pub = LocationPublisher(self.location, self.publisher.unwrap_or(""));
+
+            write!(f, " {}, {}.", loc_pub, self.date)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use super::{join::JoinAnd, locale, tex, AND_REGEX};
+
+/// The biblatex `editortype`, written after the names: `(Org.)`, `(Coord.)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditorType {
+    Organizer,
+    Editor,
+    Compiler,
+    Coordinator,
+}
+
+impl EditorType {
+    /// The `editortype` of `b`, `editor` when it is missing or unknown as in
+    /// biblatex. The non-standard `organizer` field implies an organizer.
+    pub fn from_bib(b: &Bibliography) -> Self {
+        let mut editor_type = EditorType::Editor;
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "organizer" => editor_type = EditorType::Organizer,
+                "editortype" => {
+                    editor_type = EditorType::parse(v).unwrap_or(EditorType::Editor);
+                    break;
+                }
+                _ => continue,
+            }
+        }
+
+        editor_type
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim() {
+            "organizer" => Some(EditorType::Organizer),
+            "editor" => Some(EditorType::Editor),
+            "compiler" => Some(EditorType::Compiler),
+            "coordinator" => Some(EditorType::Coordinator),
+            _ => None,
+        }
+    }
+
+    /// The role written before the names of an editor who is not the entry
+    /// element: `Organização de`.
+    pub fn contribution(self) -> &'static str {
+        let terms = locale::terms();
+
+        match self {
+            EditorType::Organizer => terms.organized_by,
+            EditorType::Editor => terms.edited_by,
+            EditorType::Compiler => terms.compiled_by,
+            EditorType::Coordinator => terms.coordinated_by,
+        }
+    }
+}
+
+impl Display for EditorType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let terms = locale::terms();
+
+        let abbreviation = match self {
+            EditorType::Organizer => terms.organizer,
+            EditorType::Editor => terms.editor,
+            EditorType::Compiler => terms.compiler,
+            EditorType::Coordinator => terms.coordinator,
+        };
+
+        write!(f, "({})", abbreviation)
+    }
+}
+
+/// Names in direct order, as ABNT writes the secondary contributors:
+/// `Maria da Silva e João Souza`.
+pub struct DirectOrder<'n>(pub &'n str);
+
+impl<'n> Display for DirectOrder<'n> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names = AND_REGEX.split(self.0).map(|name| {
+            let name = name.trim();
+
+            match name.split_once(tex::match_free_char(',')) {
+                Some((family, given)) => format!("{} {}", given.trim(), family.trim()),
+                None => name.to_string(),
+            }
+        });
+
+        let names = names.map(|n| format!("{}", tex::Text(&format!("{}", tex::NoBraces(&n)))));
+
+        write!(f, "{}", JoinAnd::new(", ", locale::terms().and, names))
+    }
+}
+
+/// Translators, annotators, commentators and the authors of the
+/// introduction and foreword, each followed by a period and a space:
+/// `Tradução de Maria da Silva. `
+#[derive(Default)]
+pub struct Contributors<'c> {
+    /// Editor of a work entered by its author, which is never read by
+    /// `from_bib` since most works enter their editors elsewhere.
+    pub editor: Option<(&'c str, EditorType)>,
+    pub translator: Option<&'c str>,
+    pub annotator: Option<&'c str>,
+    pub commentator: Option<&'c str>,
+    pub introduction: Option<&'c str>,
+    pub foreword: Option<&'c str>,
+}
+
+impl<'c> Contributors<'c> {
+    pub fn from_bib(b: &'c Bibliography) -> Self {
+        let mut contributors = Contributors::default();
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "translator" => contributors.translator = Some(v.trim()),
+                "annotator" => contributors.annotator = Some(v.trim()),
+                "commentator" => contributors.commentator = Some(v.trim()),
+                "introduction" => contributors.introduction = Some(v.trim()),
+                "foreword" => contributors.foreword = Some(v.trim()),
+                _ => continue,
+            }
+        }
+
+        contributors
+    }
+}
+
+impl<'c> Display for Contributors<'c> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let terms = locale::terms();
+
+        if let Some((names, editor_type)) = self.editor.filter(|(n, _)| !n.is_empty()) {
+            write!(f, "{} {}. ", editor_type.contribution(), DirectOrder(names))?;
+        }
+
+        let roles = [
+            (terms.translated_by, self.translator),
+            (terms.annotated_by, self.annotator),
+            (terms.commented_by, self.commentator),
+            (terms.introduction_by, self.introduction),
+            (terms.foreword_by, self.foreword),
+        ];
+
+        for (role, names) in roles {
+            if let Some(names) = names.filter(|n| !n.is_empty()) {
+                write!(f, "{} {}. ", role, DirectOrder(names))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn contributors() {
+    let contributors = Contributors {
+        translator: Some("Silva, Maria da and João Souza"),
+        foreword: Some("Cascudo, L. C."),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        format!("{contributors}"),
+        "Tradução de Maria da Silva e João Souza. Prefácio de L. C. Cascudo. ",
+    );
+}
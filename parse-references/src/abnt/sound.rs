@@ -0,0 +1,236 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use super::{
+    authors::Authors, date::Date, locale, location_publisher::LocationPublisher,
+    roles::DirectOrder, strong::Strong, tex, uppercase::title_entry,
+};
+
+/// Albums and the songs in them, as NBR 6023 writes sound documents:
+/// `GINO, A. Toque macio. Intérprete: Alcione. In: ALCIONE. <strong>Ouro e
+/// cobre</strong>. São Paulo: RCA Victor, 1988. 1 disco sonoro, faixa 1.`
+pub struct Sound<'s> {
+    /// The `author`, or else the `composer`.
+    author: &'s str,
+    composer: Option<&'s str>,
+    title: &'s str,
+    subtitle: Option<&'s str>,
+    lyricist: Option<&'s str>,
+    performer: Option<&'s str>,
+    /// Title of the album a song belongs to.
+    album: Option<&'s str>,
+    /// Artist of the album, who defaults to the performer.
+    album_artist: Option<&'s str>,
+    location: &'s str,
+    publisher: &'s str,
+    date: Date<'s>,
+    medium: Option<&'s str>,
+    track: Option<&'s str>,
+    duration: Option<&'s str>,
+}
+
+impl<'s> Sound<'s> {
+    pub fn from_bib(b: &'s Bibliography) -> Self {
+        let mut sound = Sound {
+            author: "",
+            composer: None,
+            title: "",
+            subtitle: None,
+            lyricist: None,
+            performer: None,
+            album: None,
+            album_artist: None,
+            location: "",
+            publisher: "",
+            date: Date::from_bib(b),
+            medium: None,
+            track: None,
+            duration: None,
+        };
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "author" => sound.author = v.trim(),
+                "composer" => sound.composer = Some(v.trim()),
+                "title" => sound.title = v.trim(),
+                "subtitle" => sound.subtitle = Some(v.trim()),
+                "lyricist" => sound.lyricist = Some(v.trim()),
+                "performer" => sound.performer = Some(v.trim()),
+                "booktitle" | "maintitle" => sound.album = Some(v.trim()),
+                "bookauthor" => sound.album_artist = Some(v.trim()),
+                "location" | "address" => sound.location = v.trim(),
+                "publisher" | "label" => sound.publisher = v.trim(),
+                "medium" => sound.medium = Some(v.trim()),
+                "track" | "number" => sound.track = Some(v.trim()),
+                "duration" => sound.duration = Some(v.trim()),
+                _ => continue,
+            }
+        }
+
+        if sound.author.is_empty() {
+            if let Some(composer) = sound.composer.take() {
+                sound.author = composer;
+            }
+        }
+
+        sound
+    }
+}
+
+impl<'s> Display for Sound<'s> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let terms = locale::terms();
+
+        let title = match self.subtitle {
+            Some(subtitle) => format!("{}: {}", tex::Text(self.title), tex::Text(subtitle)),
+            None => format!("{}", tex::Text(self.title)),
+        };
+
+        if self.author.is_empty() {
+            title_entry(f, self.title)?;
+
+            if let Some(subtitle) = self.subtitle {
+                write!(f, ": {}", tex::Text(subtitle))?;
+            }
+
+            f.write_str(".")?;
+        } else {
+            let mut authors = format!("{}", Authors(self.author));
+            if authors.ends_with('.') {
+                authors.pop();
+            }
+
+            // A whole album is in bold, a song within it is not.
+            if self.album.is_some() {
+                write!(f, "{}. {}.", authors, title)?;
+            } else {
+                write!(f, "{}. {}.", authors, Strong(title))?;
+            }
+        }
+
+        let credits = [
+            (terms.composer, self.composer),
+            (terms.lyrics, self.lyricist),
+            (terms.performer, self.performer),
+        ];
+
+        for (role, names) in credits {
+            if let Some(names) = names {
+                write!(f, " {}: {}.", role, DirectOrder(names))?;
+            }
+        }
+
+        if let Some(album) = self.album {
+            write!(f, " {}: ", terms.in_)?;
+
+            if let Some(artist) = self.album_artist.or(self.performer) {
+                let mut artist = format!("{}", Authors(artist));
+                if artist.ends_with('.') {
+                    artist.pop();
+                }
+
+                write!(f, "{}. ", artist)?;
+            }
+
+            write!(f, "{}.", Strong(tex::Text(album)))?;
+        }
+
+        write!(
+            f,
+            " {}, {}.",
+            LocationPublisher(self.location, self.publisher),
+            self.date
+        )?;
+
+        let description = [
+            self.medium.map(|m| format!("{}", tex::Text(m))),
+            self.track.map(|t| format!("{} {}", terms.track, t)),
+        ];
+        let description: Vec<_> = description.into_iter().flatten().collect();
+
+        match (description.is_empty(), self.duration) {
+            (false, Some(duration)) => write!(f, " {} ({}).", description.join(", "), duration)?,
+            (false, None) => write!(f, " {}.", description.join(", "))?,
+            (true, Some(duration)) => write!(f, " {}.", duration)?,
+            (true, None) => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn track() {
+    let sound = Sound {
+        author: "Gino, A.",
+        composer: None,
+        title: "Toque macio",
+        subtitle: None,
+        lyricist: None,
+        performer: Some("Alcione"),
+        album: Some("Ouro e cobre"),
+        album_artist: None,
+        location: "São Paulo",
+        publisher: "RCA Victor",
+        date: Date::from_year("1988"),
+        medium: Some("1 disco sonoro"),
+        track: Some("1"),
+        duration: Some("4 min"),
+    };
+
+    assert_eq!(
+        format!("{sound}"),
+        "GINO, A. Toque macio. Intérprete: Alcione. In: ALCIONE. <strong>Ouro e cobre</strong>. São Paulo: RCA Victor, 1988. 1 disco sonoro, faixa 1 (4 min).",
+    );
+}
+
+#[test]
+fn album() {
+    let sound = Sound {
+        author: "Fagner, R.",
+        composer: None,
+        title: "Revelação",
+        subtitle: None,
+        lyricist: None,
+        performer: None,
+        album: None,
+        album_artist: None,
+        location: "Rio de Janeiro",
+        publisher: "CBS",
+        date: Date::from_year("1988"),
+        medium: Some("1 cassete sonoro"),
+        track: None,
+        duration: Some("60 min"),
+    };
+
+    assert_eq!(
+        format!("{sound}"),
+        "FAGNER, R. <strong>Revelação</strong>. Rio de Janeiro: CBS, 1988. 1 cassete sonoro (60 min).",
+    );
+}
+
+#[test]
+fn anonymous_album() {
+    let sound = Sound {
+        author: "",
+        composer: None,
+        title: "{{Cantigas de roda} do Seridó}",
+        subtitle: None,
+        lyricist: None,
+        performer: None,
+        album: None,
+        album_artist: None,
+        location: "Natal",
+        publisher: "Cooperativa Cultural",
+        date: Date::from_year("1995"),
+        medium: Some("1 CD"),
+        track: None,
+        duration: None,
+    };
+
+    assert_eq!(
+        format!("{sound}"),
+        "CANTIGAS DE RODA do Seridó. Natal: Cooperativa Cultural, 1995. 1 CD.",
+    );
+}
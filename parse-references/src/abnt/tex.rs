@@ -1,10 +1,68 @@
 use std::{
+    borrow::Cow,
     fmt::{Display, Write},
     slice::SliceIndex,
 };
 
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+
+lazy_static! {
+    /// Accented letters, possibly between braces: `\'o`, `{\'o}`, `\~{a}`,
+    /// `\c{c}`, `\c c`, `\'{\i}`.
+    static ref ACCENT_REGEX: Regex = Regex::new(
+        r#"(\{)?\\(?:([`'^"~])\s*|(c)(?:\s+|\b))(?:\{(\\i|[A-Za-z])\}|(\\i|[A-Za-z]))(\})?"#
+    )
+    .unwrap();
+}
+
+/// The letter `base` with the accent written by the TeX command `accent`.
+fn accented(accent: char, base: char) -> Option<char> {
+    let (bases, letters) = match accent {
+        '\'' => ("aeiouyAEIOUYcCnN", "áéíóúýÁÉÍÓÚÝćĆńŃ"),
+        '`' => ("aeiouAEIOU", "àèìòùÀÈÌÒÙ"),
+        '^' => ("aeiouAEIOU", "âêîôûÂÊÎÔÛ"),
+        '"' => ("aeiouyAEIOU", "äëïöüÿÄËÏÖÜ"),
+        '~' => ("aonAON", "ãõñÃÕÑ"),
+        'c' => ("cC", "çÇ"),
+        _ => return None,
+    };
+
+    let i = bases.find(base)?;
+
+    letters.chars().nth(i)
+}
+
+fn accents(s: &str) -> Cow<'_, str> {
+    ACCENT_REGEX.replace_all(s, |c: &Captures| {
+        let accent = c.get(2).or_else(|| c.get(3)).unwrap().as_str();
+        let base = c.get(4).or_else(|| c.get(5)).unwrap().as_str();
+        let base = if base == "\\i" {
+            'i'
+        } else {
+            base.chars().next().unwrap()
+        };
+
+        let Some(letter) = accented(accent.chars().next().unwrap(), base) else {
+            return c[0].to_string();
+        };
+
+        // Braces around the accented letter only are dropped with it.
+        match (c.get(1), c.get(6)) {
+            (Some(_), Some(_)) | (None, None) => letter.to_string(),
+            (Some(_), None) => format!("{{{}", letter),
+            (None, Some(_)) => format!("{}}}", letter),
+        }
+    })
+}
+
+/// Text with TeX accents, dashes, quotes and the commands `\dots`, `\&` and
+/// `\$` replaced by the characters they stand for.
 pub struct Text<'s>(pub &'s str);
 
+/// Writes the string without the braces used to protect it from case changes.
+pub struct NoBraces<'s>(pub &'s str);
+
 enum Command {
     Dots,
     Ampersand,
@@ -45,9 +103,10 @@ enum PrintState {
 
 impl<'s> Display for Text<'s> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = accents(self.0);
         let mut state = PrintState::Normal;
 
-        for (i, c) in self.0.chars().enumerate() {
+        for (i, c) in text.char_indices() {
             state = match state {
                 PrintState::Normal => match c {
                     '-' => PrintState::Dash,
@@ -130,7 +189,7 @@ impl<'s> Display for Text<'s> {
                 },
                 PrintState::Command(start) => match c {
                     ' ' => {
-                        if let Some(cmd) = Command::from_span(self.0, start..i) {
+                        if let Some(cmd) = Command::from_span(&text, start..i) {
                             write!(f, "{} ", cmd)?;
                         }
                         PrintState::Normal
@@ -140,12 +199,20 @@ impl<'s> Display for Text<'s> {
                             f.write_char('\\')?;
                             PrintState::Normal
                         } else {
-                            if let Some(cmd) = Command::from_span(self.0, start..i) {
+                            if let Some(cmd) = Command::from_span(&text, start..i) {
                                 write!(f, "{}", cmd)?;
                             }
                             PrintState::Command(i)
                         }
                     }
+                    // A brace ends the name of a command such as `\emph{...}`.
+                    '{' | '}' if start != i - 1 => {
+                        if let Some(cmd) = Command::from_span(&text, start..i) {
+                            write!(f, "{}", cmd)?;
+                        }
+                        f.write_char(c)?;
+                        PrintState::Normal
+                    }
                     _ => PrintState::Command(start),
                 },
             };
@@ -158,7 +225,7 @@ impl<'s> Display for Text<'s> {
             PrintState::Grave => f.write_char('‘'),
             PrintState::Apostrofe => f.write_char('’'),
             PrintState::Command(start) => {
-                if let Some(cmd) = Command::from_span(self.0, start..) {
+                if let Some(cmd) = Command::from_span(&text, start..) {
                     write!(f, "{}", cmd)
                 } else {
                     Ok(())
@@ -168,6 +235,15 @@ impl<'s> Display for Text<'s> {
     }
 }
 
+impl<'s> Display for NoBraces<'s> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for c in self.0.chars().filter(|c| *c != '{' && *c != '}') {
+            f.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
 #[test]
 fn number_range() {
     assert_eq!(format!("{}", Text("23--50")), "23–50",);
@@ -204,12 +280,28 @@ fn command() {
     assert_eq!(format!("{}", Text("\\invalid")), "",);
     assert_eq!(format!("{}", Text("\\$ \\& \\dots ")), "$ & … ",);
     assert_eq!(format!("{}", Text("\\$\\&\\\\\\dots")), "$&\\…",);
+    assert_eq!(format!("{}", Text("\\emph{Dom} online")), "{Dom} online",);
+}
+
+#[test]
+fn accented_letters() {
+    assert_eq!(format!("{}", Text("Hist{\\'o}ria")), "História");
+    assert_eq!(format!("{}", Text("S\\~{a}o Jo\\~ao")), "São João");
+    assert_eq!(
+        format!("{}", Text("Assun\\c{c}\\~ao, Cear\\'a")),
+        "Assunção, Ceará"
+    );
+    assert_eq!(format!("{}", Text("Pi\\'{\\i} \\c c \\dots")), "Pií ç …");
+    assert_eq!(
+        format!("{}", Text("{\\'O}rf{\\~a}os do {\\'E}den")),
+        "Órfãos do Éden"
+    );
 }
 
 pub fn match_free_char(c: char) -> impl FnMut(char) -> bool {
     let mut count = 0u8;
 
-    return move |d: char| {
+    move |d: char| {
         match d {
             d if c == d => count == 0,
             '{' => {
@@ -234,3 +326,4 @@ fn latex_space_match() {
         Some(("Os{ }Multantes", "da Record")),
     );
 }
+
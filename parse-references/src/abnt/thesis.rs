@@ -2,7 +2,7 @@ use std::fmt::{Display, Write};
 
 use nom_bibtex::Bibliography;
 
-use super::{authors::Authors, tex};
+use super::{authors::Authors, date::Date, pages::Pagination, tex};
 
 pub struct Thesis<'t> {
     author: &'t str,
@@ -11,7 +11,11 @@ pub struct Thesis<'t> {
     thesis_type: &'t str,
     institution: &'t str,
     location: Option<&'t str>,
-    year: &'t str,
+    date: Date<'t>,
+    pagetotal: Option<&'t str>,
+    /// Theses are printed on one side of the sheet and counted in leaves,
+    /// unless `bookpagination` says otherwise.
+    pagination: Pagination,
 }
 
 impl<'t> Thesis<'t> {
@@ -23,7 +27,9 @@ impl<'t> Thesis<'t> {
             thesis_type: "",
             institution: "",
             location: None,
-            year: "",
+            date: Date::from_bib(b),
+            pagetotal: None,
+            pagination: Pagination::Folio,
         };
 
         for (k, v) in b.tags().iter() {
@@ -34,7 +40,12 @@ impl<'t> Thesis<'t> {
                 "type" => thesis.thesis_type = v.as_str(),
                 "institution" => thesis.institution = v.as_str(),
                 "location" | "address" => thesis.location = Some(v.as_str()),
-                "year" => thesis.year = v.as_str(),
+                "pagetotal" => thesis.pagetotal = Some(v.trim()),
+                "bookpagination" => {
+                    if let Some(pagination) = Pagination::from_field(v) {
+                        thesis.pagination = pagination;
+                    }
+                }
                 _ => continue,
             }
         }
@@ -45,7 +56,7 @@ impl<'t> Thesis<'t> {
 
 impl<'t> Display for Thesis<'t> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut authors = format!("{}", Authors(&self.author));
+        let mut authors = format!("{}", Authors(self.author));
         if authors.ends_with(".") {
             authors.pop();
         }
@@ -56,10 +67,15 @@ impl<'t> Display for Thesis<'t> {
             write!(f, ": {}", tex::Text(subtitle))?;
         }
 
+        write!(f, ". {}.", self.date)?;
+
+        if let Some(pagetotal) = self.pagetotal {
+            write!(f, " {} {}", pagetotal, self.pagination.abbreviation())?;
+        }
+
         write!(
             f,
-            ". {}. {} – {}",
-            self.year,
+            " {} – {}",
             tex::Text(self.thesis_type),
             tex::Text(self.institution),
         )?;
@@ -77,7 +93,9 @@ fn simple_thesis() {
     let thesis = Thesis {
         title: "Onde fica o sertão rompem-se as águas",
         author: "Dias, P. O.",
-        year: "2015",
+        date: Date::from_year("2015"),
+        pagetotal: None,
+        pagination: Pagination::Folio,
         subtitle: Some("processo de territorialização da ribeira do Apodi-Mossoró (1676–1725)"),
         thesis_type: "Dissertação (Mestrado em História)",
         institution: "Universidade Federal do Rio Grande do Norte",
@@ -89,3 +107,23 @@ fn simple_thesis() {
         "DIAS, P. O. <strong>Onde fica o sertão rompem-se as águas</strong>: processo de territorialização da ribeira do Apodi-Mossoró (1676–1725). 2015. Dissertação (Mestrado em História) – Universidade Federal do Rio Grande do Norte, Natal."
     )
 }
+
+#[test]
+fn thesis_leaves() {
+    let thesis = Thesis {
+        title: "Título",
+        author: "Dias, P. O.",
+        date: Date::from_year("2015"),
+        pagetotal: Some("215"),
+        pagination: Pagination::Folio,
+        subtitle: None,
+        thesis_type: "Dissertação (Mestrado em História)",
+        institution: "Universidade Federal do Rio Grande do Norte",
+        location: Some("Natal"),
+    };
+
+    assert_eq!(
+        format!("{thesis}"),
+        "DIAS, P. O. <strong>Título</strong>. 2015. 215 f. Dissertação (Mestrado em História) – Universidade Federal do Rio Grande do Norte, Natal."
+    )
+}
@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+use super::tex;
+
 pub struct Uppercase<'u>(pub &'u str);
 
 impl<'u> Display for Uppercase<'u> {
@@ -14,12 +16,81 @@ impl<'u> Display for Uppercase<'u> {
     }
 }
 
+/// Writes a title as the entry element, its first word in uppercase. The
+/// title is split before being formatted, so that braces and commands are
+/// kept whole: `{The troubled} land` gives `THE TROUBLED land`. Braces
+/// around the whole title only protect it and do not make it one word.
+pub fn title_entry(f: &mut std::fmt::Formatter<'_>, title: &str) -> std::fmt::Result {
+    let title = if encloses(title) {
+        &title[1..title.len() - 1]
+    } else {
+        title
+    };
+
+    let (start, end) = title
+        .split_once(tex::match_free_char(' '))
+        .unwrap_or((title, ""));
+
+    write!(f, "{}", Uppercase(&tex::Text(start).to_string()))?;
+
+    if !end.is_empty() {
+        write!(f, " {}", tex::Text(end))?;
+    }
+
+    Ok(())
+}
+
+/// Whether the first brace of `s` is closed by its last character.
+fn encloses(s: &str) -> bool {
+    let mut depth = 0usize;
+
+    s.starts_with('{')
+        && s.ends_with('}')
+        && s.char_indices().all(|(i, c)| {
+            match c {
+                '{' => depth += 1,
+                '}' => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+
+            depth > 0 || i == s.len() - 1
+        })
+}
+
 #[test]
 fn remove_braces() {
     let s = "Prado{ }Jr.";
 
+    assert_eq!(format!("{}", Uppercase(s)), "PRADO JR.",)
+}
+
+#[test]
+fn title_entries() {
+    struct Entry(&'static str);
+
+    impl Display for Entry {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            title_entry(f, self.0)
+        }
+    }
+
+    assert_eq!(
+        format!("{}", Entry("{The troubled} land")),
+        "THE TROUBLED land",
+    );
+    assert_eq!(
+        format!("{}", Entry("\\emph{Dom Casmurro} online")),
+        "DOM CASMURRO online",
+    );
+    assert_eq!(
+        format!("{}", Entry("{{The troubled} land}")),
+        "THE TROUBLED land",
+    );
     assert_eq!(
-        format!("{}", Uppercase(s)),
-        "PRADO JR.",
-    )
+        format!("{}", Entry("{Dinarte de Medeiros Mariz}")),
+        "DINARTE de Medeiros Mariz",
+    );
+    assert_eq!(format!("{}", Entry("{A}{B}")), "AB");
+    assert_eq!(format!("{}", Entry("{{A} b}{C}")), "A BC");
+    assert_eq!(format!("{}", Entry("Memórias")), "MEMÓRIAS");
 }
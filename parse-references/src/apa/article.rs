@@ -0,0 +1,115 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use crate::abnt::{pages::Pages, tex};
+
+use super::{
+    authors::Authors, date::Date, emphasis::Em, parts::PageRange, sentence_case::SentenceCase,
+};
+
+pub struct Article<'a> {
+    author: &'a str,
+    title: &'a str,
+    subtitle: Option<&'a str>,
+    journal: Option<&'a str>,
+    volume: Option<&'a str>,
+    issue: Option<&'a str>,
+    pages: Option<Pages<'a>>,
+    date: Date<'a>,
+}
+
+impl<'a> Article<'a> {
+    pub fn from_bib(b: &'a Bibliography) -> Self {
+        let mut article = Article {
+            author: "",
+            title: "",
+            subtitle: None,
+            journal: None,
+            volume: None,
+            issue: None,
+            pages: None,
+            date: Date::default(),
+        };
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "author" => article.author = v.trim(),
+                "title" => article.title = v.trim(),
+                "subtitle" => article.subtitle = Some(v.trim()),
+                "journal" | "journaltitle" => article.journal = Some(v.trim()),
+                "volume" => article.volume = Some(v.trim()),
+                "issue" | "number" => article.issue = Some(v.trim()),
+                "page" | "pages" => article.pages = Some(Pages::from_str(v.trim())),
+                "date" => article.date = Date::parse(v),
+                "year" if article.date.year.is_none() => article.date = Date::from_year(v),
+                _ => continue,
+            }
+        }
+
+        article
+    }
+}
+
+impl<'a> Display for Article<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut title = format!("{}", SentenceCase(self.title));
+
+        if let Some(subtitle) = self.subtitle {
+            title = format!("{}: {}", title, SentenceCase(subtitle));
+        }
+
+        if self.author.is_empty() {
+            write!(f, "{}. {}.", title, self.date)?;
+        } else {
+            let mut authors = format!("{}", Authors(self.author));
+            if !authors.ends_with('.') {
+                authors.push('.');
+            }
+
+            write!(f, "{} {}. {}.", authors, self.date, title)?;
+        }
+
+        if let Some(journal) = self.journal {
+            write!(f, " {}", Em(tex::Text(journal)))?;
+
+            if let Some(volume) = self.volume {
+                write!(f, ", {}", Em(volume))?;
+            }
+
+            if let Some(issue) = self.issue {
+                if self.volume.is_none() {
+                    f.write_str(", ")?;
+                }
+                write!(f, "({issue})")?;
+            }
+
+            if let Some(pages) = &self.pages {
+                write!(f, ", {}", PageRange(pages))?;
+            }
+
+            f.write_str(".")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn simple_article() {
+    let article = Article {
+        author: "Rezende, M. J.",
+        title: "Os sertões e os (des)caminhos da mudança social no {Brasil}",
+        subtitle: None,
+        journal: Some("Tempo Social: Revista de Sociologia da USP"),
+        volume: Some("13"),
+        issue: Some("2"),
+        pages: Some(Pages::Range("201", "226")),
+        date: Date::from_year("2001"),
+    };
+
+    assert_eq!(
+        format!("{article}"),
+        "Rezende, M. J. (2001). Os sertões e os (des)caminhos da mudança social no Brasil. <em>Tempo Social: Revista de Sociologia da USP</em>, <em>13</em>(2), 201–226."
+    );
+}
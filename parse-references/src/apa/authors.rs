@@ -0,0 +1,147 @@
+use std::fmt::Display;
+
+use crate::abnt::{
+    authors::{split_name, Initials},
+    join::{Join, JoinAnd},
+    tex, AND_REGEX,
+};
+
+/// APA lists up to 20 authors; longer lists keep the first 19, an ellipsis
+/// and the last author.
+const MAX_AUTHORS: usize = 20;
+
+/// Author element of a reference list entry: `Family, G. G., & Family, G.`.
+#[repr(transparent)]
+pub struct Authors<'a>(pub &'a str);
+
+/// Names as they appear after "In" in chapters: `G. G. Family`.
+#[repr(transparent)]
+pub struct GivenFirst<'a>(pub &'a str);
+
+struct FamilyFirst<'a>(&'a str);
+
+struct InitialsFirst<'a>(&'a str);
+
+impl<'a> Display for Authors<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let authors: Vec<_> = AND_REGEX.split(self.0).map(str::trim).collect();
+
+        if authors.len() > MAX_AUTHORS {
+            let first = authors[..MAX_AUTHORS - 1].iter().copied().map(FamilyFirst);
+
+            write!(
+                f,
+                "{}, . . . {}",
+                Join::new(", ", first),
+                FamilyFirst(authors[authors.len() - 1]),
+            )
+        } else {
+            write!(
+                f,
+                "{}",
+                JoinAnd::new(", ", ", & ", authors.into_iter().map(FamilyFirst))
+            )
+        }
+    }
+}
+
+impl<'a> Display for GivenFirst<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<_> = AND_REGEX.split(self.0).map(str::trim).collect();
+        let and = if names.len() > 2 { ", & " } else { " & " };
+
+        write!(
+            f,
+            "{}",
+            JoinAnd::new(", ", and, names.into_iter().map(InitialsFirst))
+        )
+    }
+}
+
+impl<'a> Display for FamilyFirst<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match split_name(self.0) {
+            Some((family, given)) => write!(
+                f,
+                "{}, {}",
+                tex::NoBraces(family.trim()),
+                Initials(given.trim())
+            ),
+            None => write!(f, "{}", tex::NoBraces(self.0)),
+        }
+    }
+}
+
+impl<'a> Display for InitialsFirst<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match split_name(self.0) {
+            Some((family, given)) => write!(
+                f,
+                "{} {}",
+                Initials(given.trim()),
+                tex::NoBraces(family.trim())
+            ),
+            None => write!(f, "{}", tex::NoBraces(self.0)),
+        }
+    }
+}
+
+/// Sort key for a name list: family names first, then initials, compared
+/// letter by letter so that "nothing precedes something".
+pub fn sort_key(names: &str) -> Vec<String> {
+    AND_REGEX
+        .split(names)
+        .map(|name| {
+            let name = format!("{}", FamilyFirst(name.trim()));
+            name.chars()
+                .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+                .flat_map(char::to_lowercase)
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn single_author() {
+    assert_eq!(format!("{}", Authors("Araújo, Gabriel")), "Araújo, G.");
+}
+
+#[test]
+fn two_authors() {
+    assert_eq!(
+        format!("{}", Authors("Araújo, G. AND Oliveira, F. I. D.")),
+        "Araújo, G., & Oliveira, F. I. D.",
+    );
+}
+
+#[test]
+fn many_authors() {
+    let names: Vec<_> = (1..=22).map(|i| format!("Author{i}, A.")).collect();
+    let names = names.join(" and ");
+
+    let output = format!("{}", Authors(&names));
+
+    assert!(output.starts_with("Author1, A., Author2, A., "));
+    assert!(output.ends_with("Author19, A., . . . Author22, A."));
+}
+
+#[test]
+fn given_first() {
+    assert_eq!(
+        format!(
+            "{}",
+            GivenFirst("Fragoso, J. and Bicalho, M. F. and Gouvêa, M. F.")
+        ),
+        "J. Fragoso, M. F. Bicalho, & M. F. Gouvêa",
+    );
+    assert_eq!(
+        format!("{}", GivenFirst("Silva, G. C. M.")),
+        "G. C. M. Silva"
+    );
+}
+
+#[test]
+fn nothing_precedes_something() {
+    assert!(sort_key("Brown, J. R.") < sort_key("Browning, A. R."));
+    assert!(sort_key("Brown, J.") < sort_key("Brown, J. and Adams, B."));
+}
@@ -0,0 +1,137 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use crate::abnt::AND_REGEX;
+
+use super::{
+    authors::Authors,
+    date::Date,
+    emphasis::Em,
+    parts::{Edition, Publisher},
+    sentence_case::SentenceCase,
+};
+
+pub struct Book<'b> {
+    author: &'b str,
+    editor: &'b str,
+    title: &'b str,
+    subtitle: Option<&'b str>,
+    edition: Option<&'b str>,
+    publisher: &'b str,
+    date: Date<'b>,
+}
+
+impl<'b> Book<'b> {
+    pub fn from_bib(b: &'b Bibliography) -> Self {
+        let mut book = Book {
+            author: "",
+            editor: "",
+            title: "",
+            subtitle: None,
+            edition: None,
+            publisher: "",
+            date: Date::default(),
+        };
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "author" => book.author = v.trim(),
+                "editor" | "organizer" => book.editor = v.trim(),
+                "title" => book.title = v.trim(),
+                "subtitle" => book.subtitle = Some(v.trim()),
+                "edition" => book.edition = Some(v.trim()),
+                "publisher" => book.publisher = v.trim(),
+                "date" => book.date = Date::parse(v),
+                "year" if book.date.year.is_none() => book.date = Date::from_year(v),
+                _ => continue,
+            }
+        }
+
+        book
+    }
+}
+
+impl<'b> Display for Book<'b> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut title = format!("{}", SentenceCase(self.title));
+
+        if let Some(subtitle) = self.subtitle {
+            title = format!("{}: {}", title, SentenceCase(subtitle));
+        }
+
+        let mut title = format!("{}", Em(title));
+
+        if let Some(edition) = self.edition {
+            title = format!("{} ({})", title, Edition(edition));
+        }
+
+        if !self.author.is_empty() {
+            let mut authors = format!("{}", Authors(self.author));
+            if !authors.ends_with('.') {
+                authors.push('.');
+            }
+
+            write!(f, "{} {}. {}.", authors, self.date, title)?;
+        } else if !self.editor.is_empty() {
+            let role = if AND_REGEX.is_match(self.editor) {
+                "Eds."
+            } else {
+                "Ed."
+            };
+
+            write!(
+                f,
+                "{} ({}). {}. {}.",
+                Authors(self.editor),
+                role,
+                self.date,
+                title
+            )?;
+        } else {
+            write!(f, "{}. {}.", title, self.date)?;
+        }
+
+        if !self.publisher.is_empty() {
+            write!(f, " {}.", Publisher(self.publisher))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn simple_book() {
+    let book = Book {
+        author: "Assunção, P.",
+        editor: "",
+        title: "Negócios {Jesuíticos}",
+        subtitle: Some("o cotidiano da administração dos bens divinos"),
+        edition: Some("2"),
+        publisher: "Editora da Universidade de São Paulo",
+        date: Date::from_year("2004"),
+    };
+
+    assert_eq!(
+        format!("{book}"),
+        "Assunção, P. (2004). <em>Negócios Jesuíticos: O cotidiano da administração dos bens divinos</em> (2nd ed.). Editora da Universidade de São Paulo."
+    );
+}
+
+#[test]
+fn edited_book() {
+    let book = Book {
+        author: "",
+        editor: "Lapa, J. R. A.",
+        title: "Modos de produção e realidade brasileira",
+        subtitle: None,
+        edition: None,
+        publisher: "Vozes",
+        date: Date::from_year("1980"),
+    };
+
+    assert_eq!(
+        format!("{book}"),
+        "Lapa, J. R. A. (Ed.). (1980). <em>Modos de produção e realidade brasileira</em>. Vozes."
+    );
+}
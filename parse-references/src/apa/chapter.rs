@@ -0,0 +1,153 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use crate::abnt::{join::Join, pages::Pages, AND_REGEX};
+
+use super::{
+    authors::{Authors, GivenFirst},
+    date::Date,
+    emphasis::Em,
+    parts::{Edition, LabeledPages, Publisher},
+    sentence_case::SentenceCase,
+};
+
+/// A part of a larger work: book chapters, contributions to edited
+/// collections and papers in conference proceedings.
+pub struct Chapter<'c> {
+    author: &'c str,
+    title: &'c str,
+    subtitle: Option<&'c str>,
+    bookauthor: &'c str,
+    editor: &'c str,
+    booktitle: &'c str,
+    booksubtitle: Option<&'c str>,
+    edition: Option<&'c str>,
+    pages: Option<Pages<'c>>,
+    publisher: &'c str,
+    date: Date<'c>,
+}
+
+impl<'c> Chapter<'c> {
+    pub fn from_bib(b: &'c Bibliography) -> Self {
+        let mut chapter = Chapter {
+            author: "",
+            title: "",
+            subtitle: None,
+            bookauthor: "",
+            editor: "",
+            booktitle: "",
+            booksubtitle: None,
+            edition: None,
+            pages: None,
+            publisher: "",
+            date: Date::default(),
+        };
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "author" => chapter.author = v.trim(),
+                "title" => chapter.title = v.trim(),
+                "subtitle" => chapter.subtitle = Some(v.trim()),
+                "bookauthor" => chapter.bookauthor = v.trim(),
+                "editor" | "organizer" => chapter.editor = v.trim(),
+                "booktitle" => chapter.booktitle = v.trim(),
+                "eventtitle" if chapter.booktitle.is_empty() => chapter.booktitle = v.trim(),
+                "booksubtitle" => chapter.booksubtitle = Some(v.trim()),
+                "edition" => chapter.edition = Some(v.trim()),
+                "page" | "pages" => chapter.pages = Some(Pages::from_str(v.trim())),
+                "publisher" => chapter.publisher = v.trim(),
+                "date" => chapter.date = Date::parse(v),
+                "year" if chapter.date.year.is_none() => chapter.date = Date::from_year(v),
+                _ => continue,
+            }
+        }
+
+        chapter
+    }
+}
+
+impl<'c> Display for Chapter<'c> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut title = format!("{}", SentenceCase(self.title));
+
+        if let Some(subtitle) = self.subtitle {
+            title = format!("{}: {}", title, SentenceCase(subtitle));
+        }
+
+        if self.author.is_empty() {
+            write!(f, "{}. {}.", title, self.date)?;
+        } else {
+            let mut authors = format!("{}", Authors(self.author));
+            if !authors.ends_with('.') {
+                authors.push('.');
+            }
+
+            write!(f, "{} {}. {}.", authors, self.date, title)?;
+        }
+
+        f.write_str(" In ")?;
+
+        if !self.bookauthor.is_empty() {
+            write!(f, "{}, ", GivenFirst(self.bookauthor))?;
+        } else if !self.editor.is_empty() {
+            let role = if AND_REGEX.is_match(self.editor) {
+                "Eds."
+            } else {
+                "Ed."
+            };
+
+            write!(f, "{} ({}), ", GivenFirst(self.editor), role)?;
+        }
+
+        let mut booktitle = format!("{}", SentenceCase(self.booktitle));
+
+        if let Some(booksubtitle) = self.booksubtitle {
+            booktitle = format!("{}: {}", booktitle, SentenceCase(booksubtitle));
+        }
+
+        write!(f, "{}", Em(booktitle))?;
+
+        let edition = self.edition.map(Edition);
+        let pages = self.pages.as_ref().map(LabeledPages);
+
+        let details = [
+            edition.as_ref().map(|e| e as &dyn Display),
+            pages.as_ref().map(|p| p as &dyn Display),
+        ];
+
+        if details.iter().any(Option::is_some) {
+            write!(f, " ({})", Join::new(", ", details.into_iter().flatten()))?;
+        }
+
+        f.write_str(".")?;
+
+        if !self.publisher.is_empty() {
+            write!(f, " {}.", Publisher(self.publisher))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn edited_chapter() {
+    let chapter = Chapter {
+        author: "Alveal, C. M. O.",
+        title: "Uma análise preliminar das sesmarias nas {Capitanias do Norte}",
+        subtitle: None,
+        bookauthor: "",
+        editor: "Silva, G. C. M.",
+        booktitle: "A época moderna e o {Brasil} colonial",
+        booksubtitle: Some("conceitos, fontes e pesquisas"),
+        edition: None,
+        pages: Some(Pages::Range("231", "242")),
+        publisher: "EDUFAL",
+        date: Date::from_year("2019"),
+    };
+
+    assert_eq!(
+        format!("{chapter}"),
+        "Alveal, C. M. O. (2019). Uma análise preliminar das sesmarias nas Capitanias do Norte. In G. C. M. Silva (Ed.), <em>A época moderna e o Brasil colonial: Conceitos, fontes e pesquisas</em> (pp. 231–242). EDUFAL."
+    );
+}
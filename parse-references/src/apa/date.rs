@@ -0,0 +1,98 @@
+use std::fmt::Display;
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+fn lookup_month(n: u8) -> Option<&'static str> {
+    if n == 0 || n > 12 {
+        None
+    } else {
+        Some(MONTHS[(n - 1) as usize])
+    }
+}
+
+/// Publication date of an entry, taken from `date` (`YYYY-MM-DD`) or `year`.
+#[derive(Default)]
+pub struct Date<'d> {
+    pub year: Option<&'d str>,
+    pub month: Option<u8>,
+    pub day: Option<u8>,
+}
+
+impl<'d> Date<'d> {
+    pub fn parse(date: &'d str) -> Self {
+        let mut parts = date.trim().split('-');
+
+        Self {
+            year: parts.next().filter(|y| !y.is_empty()),
+            month: parts.next().and_then(|m| m.parse().ok()),
+            day: parts.next().and_then(|d| d.parse().ok()),
+        }
+    }
+
+    pub fn from_year(year: &'d str) -> Self {
+        Self {
+            year: Some(year.trim()).filter(|y| !y.is_empty()),
+            ..Self::default()
+        }
+    }
+}
+
+/// `(2019, July 19)`, `(2019)` or `(n.d.)`.
+impl<'d> Display for Date<'d> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let year = if let Some(year) = self.year {
+            year
+        } else {
+            return f.write_str("(n.d.)");
+        };
+
+        match (self.month.and_then(lookup_month), self.day) {
+            (Some(month), Some(day)) => write!(f, "({year}, {month} {day})"),
+            (Some(month), None) => write!(f, "({year}, {month})"),
+            _ => write!(f, "({year})"),
+        }
+    }
+}
+
+/// Retrieval date in running text: `July 19, 2019`.
+pub struct Retrieved<'d>(pub &'d str);
+
+impl<'d> Display for Retrieved<'d> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let date = Date::parse(self.0);
+
+        match (date.year, date.month.and_then(lookup_month), date.day) {
+            (Some(year), Some(month), Some(day)) => write!(f, "{month} {day}, {year}"),
+            (Some(year), Some(month), None) => write!(f, "{month} {year}"),
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+#[test]
+fn full_date() {
+    assert_eq!(format!("{}", Date::parse("2019-07-19")), "(2019, July 19)");
+}
+
+#[test]
+fn no_date() {
+    assert_eq!(format!("{}", Date::from_year("")), "(n.d.)");
+}
+
+#[test]
+fn retrieved() {
+    assert_eq!(format!("{}", Retrieved("2019-07-19")), "July 19, 2019");
+}
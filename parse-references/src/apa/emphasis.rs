@@ -0,0 +1,9 @@
+use std::fmt::Display;
+
+pub struct Em<T>(pub T);
+
+impl<T: Display> Display for Em<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<em>{}</em>", self.0)
+    }
+}
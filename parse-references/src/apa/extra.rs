@@ -0,0 +1,59 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use crate::{
+    doi::Doi,
+    html::{Anchor, DoiAnchor},
+};
+
+/// Trailing DOI or URL of an entry. APA prefers the DOI and writes it as a
+/// `https://doi.org/` link without a final period.
+pub struct Link<'l> {
+    doi: Option<Doi>,
+    url: Option<&'l str>,
+}
+
+impl<'l> Link<'l> {
+    pub fn from_bib(b: &'l Bibliography) -> Self {
+        let mut link = Self {
+            doi: None,
+            url: None,
+        };
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "doi" => link.doi = Doi::parse(v),
+                "url" => link.url = Some(v.trim()),
+                _ => continue,
+            }
+        }
+
+        link
+    }
+}
+
+impl<'l> Display for Link<'l> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(doi) = &self.doi {
+            write!(f, " {}", DoiAnchor(doi))
+        } else if let Some(url) = self.url {
+            write!(f, " {}", Anchor::url(url))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn prefer_doi() {
+    let link = Link {
+        doi: Doi::parse("doi:10.1590/S0103-20702001000200010"),
+        url: Some("https://www.scielo.br/j/ts/a/xyz"),
+    };
+
+    assert_eq!(
+        format!("{link}"),
+        r#" <a href="https://doi.org/10.1590/S0103-20702001000200010">https://doi.org/10.1590/S0103-20702001000200010</a>"#
+    );
+}
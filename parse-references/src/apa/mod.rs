@@ -0,0 +1,253 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use nom_bibtex::Bibliography;
+
+use self::{
+    article::Article, book::Book, chapter::Chapter, extra::Link, thesis::Thesis, webpage::WebPage,
+};
+
+mod article;
+pub mod authors;
+mod book;
+mod chapter;
+pub mod date;
+pub mod emphasis;
+mod extra;
+pub mod parts;
+pub mod sentence_case;
+mod thesis;
+mod webpage;
+
+/// A bibliography entry rendered according to the APA 7th edition.
+#[repr(transparent)]
+#[derive(PartialEq, Eq)]
+pub struct Apa<'b>(pub &'b Bibliography);
+
+impl<'b> PartialOrd for Apa<'b> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Entries are ordered by author names, then by year (undated works first)
+/// and then by title. Works without author are placed by their title,
+/// disregarding a leading article.
+impl<'b> Ord for Apa<'b> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        fn find_sort_keys(b: &Bibliography) -> (Vec<String>, Option<&str>, String) {
+            let mut author = None;
+            let mut editor = None;
+            let mut title = "";
+            let mut year = None;
+
+            for (k, v) in b.tags() {
+                match k.as_str() {
+                    "author" => author = Some(v.as_str()),
+                    "editor" => editor = Some(v.as_str()),
+                    "title" => title = v.trim(),
+                    "year" => year = Some(v.trim()),
+                    "date" if year.is_none() => year = v.trim().split('-').next(),
+                    _ => {}
+                }
+            }
+
+            let title: String = title
+                .chars()
+                .filter(|c| *c != '{' && *c != '}')
+                .flat_map(char::to_lowercase)
+                .collect();
+
+            let title = ["a ", "an ", "the "]
+                .iter()
+                .find_map(|a| title.strip_prefix(a))
+                .map(str::to_string)
+                .unwrap_or(title);
+
+            let names = author
+                .or(editor)
+                .map(authors::sort_key)
+                .unwrap_or_else(|| vec![title.clone()]);
+
+            (names, year.filter(|y| !y.is_empty()), title)
+        }
+
+        find_sort_keys(self.0).cmp(&find_sort_keys(other.0))
+    }
+}
+
+impl<'b> Display for Apa<'b> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0.entry_type() {
+            "article" => write!(f, "{}", Article::from_bib(self.0)),
+            "book" | "mvbook" | "collection" | "mvcollection" | "proceedings" | "reference"
+            | "mvreference" => write!(f, "{}", Book::from_bib(self.0)),
+            "inbook" | "incollection" | "inproceedings" | "inreference" => {
+                write!(f, "{}", Chapter::from_bib(self.0))
+            }
+            "thesis" | "phdthesis" | "mastersthesis" => write!(f, "{}", Thesis::from_bib(self.0)),
+            // Other types, such as movies and periodicals, are written as a
+            // stand-alone work with its author, date, title and link.
+            _ => write!(f, "{}", WebPage::from_bib(self.0)),
+        }?;
+
+        write!(f, "{}", Link::from_bib(self.0))
+    }
+}
+
+#[test]
+fn article_from_bib() {
+    let entry = r"
+    @article{Azevedo1959,
+        title        = {Aldeias e aldeamentos},
+        author       = {Azevedo, A.},
+        year         = 1959,
+        number       = 33,
+        pages        = 27,
+        journaltitle = {Boletim Paulista de Geografia}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Apa(bib)),
+        "Azevedo, A. (1959). Aldeias e aldeamentos. <em>Boletim Paulista de Geografia</em>, (33), 27.",
+    );
+}
+
+#[test]
+fn article_with_doi() {
+    let entry = r"
+    @article{Rezende2001,
+        author   = {Rezende, M. J. and Souza, A.},
+        title    = {Os sertões e os (des)caminhos da mudança social no {Brasil}},
+        journal  = {Tempo Social},
+        volume   = {13},
+        number   = {2},
+        year     = {2001},
+        pages    = {201--226},
+        doi      = {https://doi.org/10.1590/S0103-20702001000200010}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Apa(bib)),
+        r#"Rezende, M. J., & Souza, A. (2001). Os sertões e os (des)caminhos da mudança social no Brasil. <em>Tempo Social</em>, <em>13</em>(2), 201–226. <a href="https://doi.org/10.1590/S0103-20702001000200010">https://doi.org/10.1590/S0103-20702001000200010</a>"#,
+    );
+}
+
+#[test]
+fn inbook_from_bib() {
+    let entry = r"
+    @inbook{Fragoso2001,
+        title        = {A formação da economia colonial no {Rio de Janeiro}},
+        author       = {Fragoso, J. A.},
+        year         = 2001,
+        booktitle    = {O {Antigo Regime} nos trópicos},
+        publisher    = {Civilização Brasileira},
+        address      = {Rio de Janeiro},
+        pages        = {29--71},
+        bookauthor   = {Fragoso, J. and Bicalho, M. F. and Gouvêa, M. F.},
+        edition      = 2
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Apa(bib)),
+        "Fragoso, J. A. (2001). A formação da economia colonial no Rio de Janeiro. In J. Fragoso, M. F. Bicalho, & M. F. Gouvêa, <em>O Antigo Regime nos trópicos</em> (2nd ed., pp. 29–71). Civilização Brasileira.",
+    );
+}
+
+#[test]
+fn sort_order() {
+    let entries = r"
+    @book{B, author = {Browning, A. R.}, title = {B}, year = {1990}}
+    @book{C, author = {Brown, J. R.}, title = {C}, year = {2001}}
+    @book{D, author = {Brown, J. R.}, title = {D}, year = {1999}}
+    @book{E, author = {Brown, J. R.}, title = {E}}
+    @online{F, title = {The history of Natal}}
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entries).expect("valid bibtex");
+
+    let mut bibs: Vec<_> = bibtex.bibliographies().iter().map(Apa).collect();
+    bibs.sort();
+
+    let keys: Vec<_> = bibs.iter().map(|b| b.0.citation_key()).collect();
+
+    assert_eq!(keys, ["E", "D", "C", "B", "F"]);
+}
+
+#[test]
+fn webpage_links() {
+    let entries = r"
+    @online{Mariz,
+        title        = {{Dinarte de Medeiros Mariz}},
+        organization = {CPDOC},
+        url          = {http://www.fgv.br/cpdoc/verbete},
+        urldate      = {2019-07-19}
+    }
+    @online{Dataset,
+        author       = {Souza, A.},
+        title        = {Sesmarias do {Rio Grande}},
+        year         = {2020},
+        url          = {http://example.org/sesmarias},
+        urldate      = {2021-03-02},
+        doi          = {doi:10.1000/xyz}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entries).expect("valid bibtex");
+
+    let bibs = bibtex.bibliographies();
+
+    assert_eq!(
+        format!("{}", Apa(&bibs[0])),
+        r#"<em>Dinarte de Medeiros Mariz</em>. (n.d.). CPDOC. Retrieved July 19, 2019, from <a href="http://www.fgv.br/cpdoc/verbete">http://www.fgv.br/cpdoc/verbete</a>"#,
+    );
+    assert_eq!(
+        format!("{}", Apa(&bibs[1])),
+        r#"Souza, A. (2020). <em>Sesmarias do Rio Grande</em>. <a href="https://doi.org/10.1000/xyz">https://doi.org/10.1000/xyz</a>"#,
+    );
+}
+
+#[test]
+fn other_types() {
+    let entries = r"
+    @proceedings{EEH2016,
+        editor     = {Dias, P. O.},
+        title      = {Anais do {Encontro Estadual de História}},
+        location   = {Guarabira},
+        publisher  = {ANPUH-PB},
+        year       = {2016}
+    }
+    @movie{Central1998,
+        title      = {Central do {Brasil}},
+        director   = {Salles, Walter},
+        year       = {1998}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entries).expect("valid bibtex");
+
+    let bibs = bibtex.bibliographies();
+
+    assert_eq!(
+        format!("{}", Apa(&bibs[0])),
+        "Dias, P. O. (Ed.). (2016). <em>Anais do Encontro Estadual de História</em>. ANPUH-PB.",
+    );
+    assert_eq!(
+        format!("{}", Apa(&bibs[1])),
+        "<em>Central do Brasil</em>. (1998).",
+    );
+}
@@ -0,0 +1,86 @@
+use std::fmt::Display;
+
+use crate::abnt::{join::Join, pages::Pages, tex, AND_REGEX};
+
+/// Page numbers without label, as used for journal articles: `201–226`.
+pub struct PageRange<'p>(pub &'p Pages<'p>);
+
+impl<'p> Display for PageRange<'p> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Pages::Single(page) => write!(f, "{page}"),
+            Pages::Range(first, last) => write!(f, "{first}–{last}"),
+            Pages::List(pages) => write!(f, "{}", Join::new(", ", pages.iter().map(PageRange))),
+        }
+    }
+}
+
+/// Page numbers with label, as used for chapters: `p. 5` or `pp. 29–71`.
+pub struct LabeledPages<'p>(pub &'p Pages<'p>);
+
+impl<'p> Display for LabeledPages<'p> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Pages::Single(_) => write!(f, "p. {}", PageRange(self.0)),
+            Pages::Range(..) | Pages::List(_) => write!(f, "pp. {}", PageRange(self.0)),
+        }
+    }
+}
+
+/// English edition statement: `2nd ed.`.
+pub struct Edition<'e>(pub &'e str);
+
+impl<'e> Display for Edition<'e> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let n: u32 = if let Ok(n) = self.0.parse() {
+            n
+        } else {
+            return write!(f, "{}", tex::Text(self.0));
+        };
+
+        let suffix = match (n % 10, n % 100) {
+            (_, 11..=13) => "th",
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        };
+
+        write!(f, "{n}{suffix} ed.")
+    }
+}
+
+/// Publishers separated by semicolons. APA 7 omits the publisher location.
+pub struct Publisher<'p>(pub &'p str);
+
+impl<'p> Display for Publisher<'p> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let publishers = AND_REGEX.split(self.0).map(|p| tex::Text(p.trim()));
+
+        write!(f, "{}", Join::new("; ", publishers))
+    }
+}
+
+#[test]
+fn page_range() {
+    assert_eq!(
+        format!("{}", LabeledPages(&Pages::Range("29", "71"))),
+        "pp. 29–71"
+    );
+    assert_eq!(format!("{}", PageRange(&Pages::Single("27"))), "27");
+}
+
+#[test]
+fn edition() {
+    assert_eq!(format!("{}", Edition("2")), "2nd ed.");
+    assert_eq!(format!("{}", Edition("11")), "11th ed.");
+    assert_eq!(format!("{}", Edition("21")), "21st ed.");
+}
+
+#[test]
+fn publishers() {
+    assert_eq!(
+        format!("{}", Publisher("Itatiaia AND EDUSP")),
+        "Itatiaia; EDUSP"
+    );
+}
@@ -0,0 +1,73 @@
+use std::fmt::Display;
+
+use crate::abnt::tex;
+
+/// Renders a title in sentence case: only the first word, the first word
+/// after a colon, question or exclamation mark, acronyms and text protected
+/// by braces keep their capitals.
+pub struct SentenceCase<'s>(pub &'s str);
+
+impl<'s> Display for SentenceCase<'s> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut out = String::with_capacity(self.0.len());
+        let mut depth = 0u8;
+        let mut capitalize = true;
+
+        for word in self.0.split(' ') {
+            if !out.is_empty() {
+                out.push(' ');
+            }
+
+            let acronym = is_acronym(word);
+
+            for c in word.chars() {
+                match c {
+                    '{' => depth = depth.saturating_add(1),
+                    '}' => depth = depth.saturating_sub(1),
+                    c if depth > 0 || acronym || !c.is_alphabetic() => {
+                        if c.is_alphabetic() {
+                            capitalize = false;
+                        }
+                        out.push(c)
+                    }
+                    c if capitalize => {
+                        out.extend(c.to_uppercase());
+                        capitalize = false;
+                    }
+                    c => out.extend(c.to_lowercase()),
+                }
+            }
+
+            if depth == 0 && word.ends_with([':', '?', '!']) {
+                capitalize = true;
+            }
+        }
+
+        write!(f, "{}", tex::Text(&out))
+    }
+}
+
+fn is_acronym(word: &str) -> bool {
+    let mut letters = word.chars().filter(|c| c.is_alphabetic());
+
+    letters.clone().count() > 1 && letters.all(char::is_uppercase)
+}
+
+#[test]
+fn sentence_case() {
+    assert_eq!(
+        format!("{}", SentenceCase("Negócios Jesuíticos: O Cotidiano")),
+        "Negócios jesuíticos: O cotidiano",
+    );
+}
+
+#[test]
+fn keep_protected() {
+    assert_eq!(
+        format!(
+            "{}",
+            SentenceCase("Relatório do {Departamento Nacional} de Obras (DNOCS)")
+        ),
+        "Relatório do Departamento Nacional de obras (DNOCS)",
+    );
+}
@@ -0,0 +1,101 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use crate::abnt::tex;
+
+use super::{authors::Authors, date::Date, emphasis::Em, sentence_case::SentenceCase};
+
+pub struct Thesis<'t> {
+    author: &'t str,
+    title: &'t str,
+    subtitle: Option<&'t str>,
+    thesis_type: &'t str,
+    institution: &'t str,
+    date: Date<'t>,
+}
+
+impl<'t> Thesis<'t> {
+    pub fn from_bib(b: &'t Bibliography) -> Self {
+        let mut thesis = Thesis {
+            author: "",
+            title: "",
+            subtitle: None,
+            thesis_type: b.entry_type(),
+            institution: "",
+            date: Date::default(),
+        };
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "author" => thesis.author = v.trim(),
+                "title" => thesis.title = v.trim(),
+                "subtitle" => thesis.subtitle = Some(v.trim()),
+                "type" => thesis.thesis_type = v.trim(),
+                "institution" | "school" => thesis.institution = v.trim(),
+                "date" => thesis.date = Date::parse(v),
+                "year" if thesis.date.year.is_none() => thesis.date = Date::from_year(v),
+                _ => continue,
+            }
+        }
+
+        thesis
+    }
+}
+
+/// Maps biblatex's thesis type keys to the APA description.
+fn describe(thesis_type: &str) -> &str {
+    match thesis_type {
+        "phdthesis" => "Doctoral dissertation",
+        "mathesis" | "mastersthesis" => "Master's thesis",
+        "thesis" => "Thesis",
+        t => t,
+    }
+}
+
+impl<'t> Display for Thesis<'t> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut authors = format!("{}", Authors(self.author));
+        if !authors.ends_with('.') {
+            authors.push('.');
+        }
+
+        let mut title = format!("{}", SentenceCase(self.title));
+
+        if let Some(subtitle) = self.subtitle {
+            title = format!("{}: {}", title, SentenceCase(subtitle));
+        }
+
+        write!(
+            f,
+            "{} {}. {} [{}",
+            authors,
+            self.date,
+            Em(title),
+            tex::Text(describe(self.thesis_type)),
+        )?;
+
+        if !self.institution.is_empty() {
+            write!(f, ", {}", tex::Text(self.institution))?;
+        }
+
+        f.write_str("].")
+    }
+}
+
+#[test]
+fn simple_thesis() {
+    let thesis = Thesis {
+        author: "Dias, T. A.",
+        title: "Dinâmicas mercantis coloniais",
+        subtitle: Some("capitania do {Rio Grande do Norte} (1760--1821)"),
+        thesis_type: "mathesis",
+        institution: "Universidade Federal do Rio Grande do Norte",
+        date: Date::from_year("2011"),
+    };
+
+    assert_eq!(
+        format!("{thesis}"),
+        "Dias, T. A. (2011). <em>Dinâmicas mercantis coloniais: Capitania do Rio Grande do Norte (1760–1821)</em> [Master’s thesis, Universidade Federal do Rio Grande do Norte]."
+    );
+}
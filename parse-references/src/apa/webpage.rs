@@ -0,0 +1,116 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use crate::{abnt::tex, doi::Doi};
+
+use super::{
+    authors::Authors,
+    date::{Date, Retrieved},
+    emphasis::Em,
+    sentence_case::SentenceCase,
+};
+
+/// Web pages and other stand-alone online documents. The site name is taken
+/// from `organization`, `publisher` or `maintitle`. The DOI or URL itself is
+/// written by `Link`.
+pub struct WebPage<'w> {
+    author: &'w str,
+    title: &'w str,
+    subtitle: Option<&'w str>,
+    site: Option<&'w str>,
+    note: Option<&'w str>,
+    doi: Option<Doi>,
+    url: Option<&'w str>,
+    url_date: Option<&'w str>,
+    date: Date<'w>,
+}
+
+impl<'w> WebPage<'w> {
+    pub fn from_bib(b: &'w Bibliography) -> Self {
+        let mut page = WebPage {
+            author: "",
+            title: "",
+            subtitle: None,
+            site: None,
+            note: None,
+            doi: None,
+            url: None,
+            url_date: None,
+            date: Date::default(),
+        };
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "author" => page.author = v.trim(),
+                "title" => page.title = v.trim(),
+                "subtitle" => page.subtitle = Some(v.trim()),
+                "organization" | "publisher" | "maintitle" => page.site = Some(v.trim()),
+                "note" => page.note = Some(v.trim().trim_end_matches('.')),
+                "doi" => page.doi = Doi::parse(v),
+                "url" => page.url = Some(v.trim()),
+                "urldate" => page.url_date = Some(v.trim()),
+                "date" => page.date = Date::parse(v),
+                "year" if page.date.year.is_none() => page.date = Date::from_year(v),
+                _ => continue,
+            }
+        }
+
+        page
+    }
+}
+
+impl<'w> Display for WebPage<'w> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut title = format!("{}", SentenceCase(self.title));
+
+        if let Some(subtitle) = self.subtitle {
+            title = format!("{}: {}", title, SentenceCase(subtitle));
+        }
+
+        if self.author.is_empty() {
+            write!(f, "{}. {}.", Em(title), self.date)?;
+        } else {
+            let mut authors = format!("{}", Authors(self.author));
+            if !authors.ends_with('.') {
+                authors.push('.');
+            }
+
+            write!(f, "{} {}. {}.", authors, self.date, Em(title))?;
+        }
+
+        if let Some(site) = self.site {
+            write!(f, " {}.", tex::Text(site))?;
+        }
+
+        if let Some(note) = self.note {
+            write!(f, " {}.", tex::Text(note))?;
+        }
+
+        // Only a page read at its URL has a retrieval date.
+        match (&self.doi, self.url, self.url_date) {
+            (None, Some(_), Some(date)) => write!(f, " Retrieved {}, from", Retrieved(date)),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[test]
+fn simple_webpage() {
+    let page = WebPage {
+        author: "",
+        title: "{Dinarte de Medeiros Mariz}",
+        subtitle: None,
+        site: Some("CPDOC"),
+        note: None,
+        doi: None,
+        url: Some("http://www.fgv.br/cpdoc/acervo/dicionarios/verbete-biografico/dinarte-de-medeiros-mariz"),
+        url_date: Some("2019-07-19"),
+        date: Date::default(),
+    };
+
+    assert_eq!(
+        format!("{page}"),
+        r#"<em>Dinarte de Medeiros Mariz</em>. (n.d.). CPDOC. Retrieved July 19, 2019, from"#
+    );
+}
@@ -0,0 +1,217 @@
+use std::fmt::Write;
+
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+
+lazy_static! {
+    /// Pandoc citations: `[@key]`, `[see @a, p. 3; -@b]`.
+    static ref PANDOC_REGEX: Regex = Regex::new(r"\[([^\[\]]*@[^\[\]]*)\]").unwrap();
+    static ref PANDOC_KEY_REGEX: Regex =
+        Regex::new(r"-?@(\w(?:[\w:.#$%&+?<>~/-]*\w)?)(?:,\s*([^;]+))?").unwrap();
+    /// LaTeX citations: `\cite{a,b}`, `\parencite[p. 3]{a}`, `\textcite{a}`...
+    static ref LATEX_REGEX: Regex =
+        Regex::new(r"\\[a-zA-Z]*cite[a-zA-Z]*\*?(?:\[([^\]]*)\])?(?:\[([^\]]*)\])?\{([^}]*)\}")
+            .unwrap();
+}
+
+/// How numbers replace the citation markers in the text.
+#[derive(Clone, Copy)]
+pub enum Marker {
+    /// `[1], [3]–[5]`, as used by IEEE.
+    Brackets,
+    /// `(1, 3–5)`, as used by Vancouver and the ABNT numeric system.
+    Parentheses,
+}
+
+/// Citation keys in the order they are first cited in an article.
+#[derive(Default)]
+pub struct Citations {
+    keys: Vec<String>,
+}
+
+struct Cite<'c> {
+    key: &'c str,
+    locator: Option<&'c str>,
+}
+
+fn pandoc_cites(group: &str) -> Vec<Cite<'_>> {
+    PANDOC_KEY_REGEX
+        .captures_iter(group)
+        .map(|c| Cite {
+            key: c.get(1).unwrap().as_str(),
+            locator: c.get(2).map(|l| l.as_str().trim()),
+        })
+        .collect()
+}
+
+fn latex_cites<'c>(captures: &Captures<'c>) -> Vec<Cite<'c>> {
+    // With a single optional argument it is the postnote, with two the
+    // second one is.
+    let locator = captures
+        .get(2)
+        .or_else(|| captures.get(1))
+        .map(|l| l.as_str().trim())
+        .filter(|l| !l.is_empty());
+
+    captures
+        .get(3)
+        .unwrap()
+        .as_str()
+        .split(',')
+        .map(str::trim)
+        .filter(|k| !k.is_empty())
+        .map(|key| Cite { key, locator })
+        .collect()
+}
+
+impl Citations {
+    /// Scans Pandoc (`[@key]`) and LaTeX (`\cite{key}`) citations.
+    pub fn scan(text: &str) -> Self {
+        let mut found: Vec<(usize, &str)> = Vec::new();
+
+        for c in PANDOC_REGEX.captures_iter(text) {
+            let start = c.get(0).unwrap().start();
+            let cites = pandoc_cites(c.get(1).unwrap().as_str());
+            found.extend(cites.into_iter().map(|cite| (start, cite.key)));
+        }
+
+        for c in LATEX_REGEX.captures_iter(text) {
+            let start = c.get(0).unwrap().start();
+            found.extend(latex_cites(&c).into_iter().map(|cite| (start, cite.key)));
+        }
+
+        found.sort_by_key(|(start, _)| *start);
+
+        let mut citations = Self::default();
+
+        for (_, key) in found {
+            citations.push(key);
+        }
+
+        citations
+    }
+
+    pub fn push(&mut self, key: &str) {
+        if !self.keys.iter().any(|k| k == key) {
+            self.keys.push(key.to_string());
+        }
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.keys.iter().map(String::as_str)
+    }
+
+    pub fn number(&self, key: &str) -> Option<usize> {
+        self.keys.iter().position(|k| k == key).map(|i| i + 1)
+    }
+
+    /// Replaces every citation marker in `text` by the matching numbers.
+    /// Unknown keys are left as they are.
+    pub fn replace(&self, text: &str, marker: Marker) -> String {
+        let text = PANDOC_REGEX.replace_all(text, |c: &Captures| {
+            self.render(&pandoc_cites(&c[1]), marker)
+                .unwrap_or_else(|| c[0].to_string())
+        });
+
+        LATEX_REGEX
+            .replace_all(&text, |c: &Captures| {
+                self.render(&latex_cites(c), marker)
+                    .unwrap_or_else(|| c[0].to_string())
+            })
+            .into_owned()
+    }
+
+    fn render(&self, cites: &[Cite], marker: Marker) -> Option<String> {
+        let mut numbers = cites
+            .iter()
+            .map(|c| self.number(c.key))
+            .collect::<Option<Vec<_>>>()?;
+
+        if numbers.is_empty() {
+            return None;
+        }
+
+        let mut out = String::new();
+
+        if let [Cite {
+            locator: Some(locator),
+            ..
+        }] = cites
+        {
+            match marker {
+                Marker::Brackets => write!(out, "[{}, {}]", numbers[0], locator),
+                Marker::Parentheses => write!(out, "({}, {})", numbers[0], locator),
+            }
+            .unwrap();
+
+            return Some(out);
+        }
+
+        numbers.sort_unstable();
+        numbers.dedup();
+
+        let mut ranges: Vec<(usize, usize)> = Vec::new();
+
+        for n in numbers {
+            match ranges.last_mut() {
+                Some((_, last)) if *last + 1 == n => *last = n,
+                _ => ranges.push((n, n)),
+            }
+        }
+
+        let ranges = ranges
+            .into_iter()
+            .map(|(first, last)| match (marker, last - first) {
+                (Marker::Brackets, 0) => format!("[{first}]"),
+                (Marker::Brackets, 1) => format!("[{first}], [{last}]"),
+                (Marker::Brackets, _) => format!("[{first}]–[{last}]"),
+                (Marker::Parentheses, 0) => format!("{first}"),
+                (Marker::Parentheses, 1) => format!("{first}, {last}"),
+                (Marker::Parentheses, _) => format!("{first}–{last}"),
+            });
+
+        let ranges: Vec<_> = ranges.collect();
+
+        match marker {
+            Marker::Brackets => out.push_str(&ranges.join(", ")),
+            Marker::Parentheses => write!(out, "({})", ranges.join(", ")).unwrap(),
+        }
+
+        Some(out)
+    }
+}
+
+#[test]
+fn citation_order() {
+    let text = r"
+    As shown in [@Dias2011; @Alveal2019, p. 3] and \cite{Assuncao2004,Dias2011},
+    the land grants [see @Alveal2019] \parencite[p. 5]{Lapa1980modos}.
+    ";
+
+    let citations = Citations::scan(text);
+
+    assert_eq!(
+        citations.keys().collect::<Vec<_>>(),
+        ["Dias2011", "Alveal2019", "Assuncao2004", "Lapa1980modos"],
+    );
+}
+
+#[test]
+fn replace_markers() {
+    let text = "Text [@b; @a; @c], \\cite{d} and [@a, p. 3] [@unknown].";
+
+    let mut citations = Citations::default();
+
+    for key in ["a", "b", "c", "d"] {
+        citations.push(key);
+    }
+
+    assert_eq!(
+        citations.replace(text, Marker::Brackets),
+        "Text [1]–[3], [4] and [1, p. 3] [@unknown].",
+    );
+    assert_eq!(
+        citations.replace(text, Marker::Parentheses),
+        "Text (1–3), (4) and (1, p. 3) [@unknown].",
+    );
+}
@@ -0,0 +1,259 @@
+use std::collections::BTreeMap;
+
+use hayagriva::citationberg::json::{
+    DateValue, Item, LiteralName, NameItem, NameValue, Value, VecDate, VecDateRange,
+};
+use nom_bibtex::Bibliography;
+
+use crate::{
+    abnt::{tex, AND_REGEX},
+    doi::Doi,
+};
+
+/// Maps a BibLaTeX entry type to its CSL item type.
+fn item_type(b: &Bibliography) -> &'static str {
+    let has_journal = b
+        .tags()
+        .iter()
+        .any(|(k, _)| k == "journal" || k == "journaltitle");
+    let subtype = b
+        .tags()
+        .iter()
+        .find(|(k, _)| k == "entrysubtype")
+        .map(|(_, v)| v.trim());
+
+    match b.entry_type() {
+        "article" if subtype == Some("newspaper") => "article-newspaper",
+        "article" if subtype == Some("magazine") => "article-magazine",
+        "article" if has_journal => "article-journal",
+        "article" => "article",
+        "book" | "mvbook" | "collection" | "mvcollection" | "proceedings" | "reference" => "book",
+        "inbook" | "incollection" | "inreference" => "chapter",
+        "inproceedings" => "paper-conference",
+        "thesis" | "phdthesis" | "mastersthesis" => "thesis",
+        "report" | "techreport" => "report",
+        "periodical" => "periodical",
+        "online" => "webpage",
+        "movie" | "video" => "motion_picture",
+        "audio" | "music" => "song",
+        "image" | "artwork" => "graphic",
+        "map" => "map",
+        "interview" => "interview",
+        "letter" => "personal_communication",
+        "speech" => "speech",
+        "misc" if subtype == Some("interview") => "interview",
+        "misc" if subtype == Some("letter") => "personal_communication",
+        "misc" if subtype == Some("speech") => "speech",
+        _ => "document",
+    }
+}
+
+/// Plain text for a tag value: TeX ligatures and commands resolved and case
+/// protecting braces removed.
+fn text(v: &str) -> Value {
+    let v = format!("{}", tex::NoBraces(v.trim()));
+    Value::String(format!("{}", tex::Text(&v)))
+}
+
+fn names(v: &str) -> Value {
+    Value::Names(AND_REGEX.split(v).map(str::trim).map(name).collect())
+}
+
+/// `Family, Given` or `Given Family`; anything else, like a braced
+/// institution, is kept literally.
+fn name(n: &str) -> NameValue {
+    let parts = n
+        .split_once(tex::match_free_char(','))
+        .or_else(|| {
+            // The braces are only balanced when scanning forward.
+            let mut free_space = tex::match_free_char(' ');
+
+            n.char_indices()
+                .filter(|(_, c)| free_space(*c))
+                .last()
+                .map(|(i, _)| n.split_at(i))
+                .map(|(given, family)| (family, given))
+        })
+        .map(|(family, given)| (family.trim(), given.trim()));
+
+    let plain = |s: &str| format!("{}", tex::NoBraces(s));
+
+    match parts {
+        Some((family, given)) => NameValue::Item(NameItem {
+            family: plain(family),
+            given: Some(plain(given)),
+            non_dropping_particle: None,
+            dropping_particle: None,
+            suffix: None,
+        }),
+        None => NameValue::Literal(LiteralName { literal: plain(n) }),
+    }
+}
+
+/// `2019-07-19`, `2019-07` or `2019`. Only the start of an EDTF range is
+/// kept, without its qualifiers.
+fn date_parts(v: &str) -> Vec<i16> {
+    let start = v.split('/').next().unwrap_or("");
+
+    start
+        .trim()
+        .trim_end_matches(['?', '~', '%'])
+        .split('-')
+        .map_while(|p| p.trim().parse().ok())
+        .take(3)
+        .collect()
+}
+
+fn date(parts: Vec<i16>) -> Value {
+    Value::Date(DateValue::DateParts {
+        date_parts: VecDateRange(vec![VecDate(parts)]),
+        literal: None,
+        season: None,
+    })
+}
+
+/// Converts the entry tags into a CSL-JSON item.
+pub fn item(b: &Bibliography) -> Item {
+    let mut map = BTreeMap::new();
+
+    map.insert("id".into(), Value::String(b.citation_key().into()));
+    map.insert("type".into(), Value::String(item_type(b).into()));
+
+    let mut title = None;
+    let mut subtitle = None;
+    let mut issued: Vec<i16> = Vec::new();
+    let mut year: Option<i16> = None;
+    let mut month: Option<i16> = None;
+
+    for (k, v) in b.tags().iter() {
+        let (key, value) = match k.as_str() {
+            "title" => {
+                title = Some(v);
+                continue;
+            }
+            "subtitle" => {
+                subtitle = Some(v);
+                continue;
+            }
+            "date" => {
+                issued = date_parts(v);
+                continue;
+            }
+            "eventdate" => ("event-date", date(date_parts(v))),
+            "year" => {
+                year = v.trim().parse().ok();
+                continue;
+            }
+            "month" => {
+                month = v.trim().parse().ok();
+                continue;
+            }
+            "author" => ("author", names(v)),
+            "editor" | "organizer" => ("editor", names(v)),
+            "bookauthor" => ("container-author", names(v)),
+            "translator" => ("translator", names(v)),
+            "director" => ("director", names(v)),
+            "producer" => ("producer", names(v)),
+            "composer" => ("composer", names(v)),
+            "performer" => ("performer", names(v)),
+            "interviewer" => ("interviewer", names(v)),
+            "recipient" => ("recipient", names(v)),
+            "shorttitle" => ("title-short", text(v)),
+            "journal" | "journaltitle" | "booktitle" => ("container-title", text(v)),
+            "shortjournal" => ("container-title-short", text(v)),
+            "eventtitle" => ("event-title", text(v)),
+            "venue" => ("event-place", text(v)),
+            "series" => ("collection-title", text(v)),
+            "volume" => ("volume", text(v)),
+            "volumes" => ("number-of-volumes", text(v)),
+            "number" | "issue" => ("issue", text(v)),
+            "part" => ("part-number", text(v)),
+            "section" => ("section", text(v)),
+            "edition" => ("edition", text(v)),
+            "page" | "pages" => ("page", text(v)),
+            "pagetotal" => ("number-of-pages", text(v)),
+            "location" | "address" => ("publisher-place", text(v)),
+            "publisher" | "institution" | "school" => ("publisher", text(v)),
+            "type" => ("genre", text(v)),
+            "medium" => ("medium", text(v)),
+            "duration" | "dimensions" => ("dimensions", text(v)),
+            "scale" => ("scale", text(v)),
+            "isbn" => ("ISBN", text(v)),
+            "issn" => ("ISSN", text(v)),
+            "url" => ("URL", Value::String(v.trim().into())),
+            "urldate" => ("accessed", date(date_parts(v))),
+            "origdate" => ("original-date", date(date_parts(v))),
+            "origlocation" => ("original-publisher-place", text(v)),
+            "origpublisher" => ("original-publisher", text(v)),
+            "note" | "howpublished" => ("note", text(v)),
+            "langid" | "language" => ("language", text(v)),
+            "doi" => match Doi::parse(v) {
+                Some(doi) => ("DOI", Value::String(doi.name().into())),
+                None => continue,
+            },
+            _ => continue,
+        };
+
+        map.insert(key.into(), value);
+    }
+
+    if let Some(title) = title {
+        let title = match subtitle {
+            Some(subtitle) => format!("{}: {}", title.trim(), subtitle.trim()),
+            None => title.to_string(),
+        };
+
+        map.insert("title".into(), text(&title));
+    }
+
+    if issued.is_empty() {
+        issued.extend(year);
+        issued.extend(year.and(month));
+    }
+
+    if !issued.is_empty() {
+        map.insert("issued".into(), date(issued));
+    }
+
+    Item(map)
+}
+
+#[test]
+fn article_item() {
+    let entry = r"
+    @article{Rezende2001,
+        author   = {Rezende, Maria José de and {Instituto Histórico}},
+        title    = {Os sertões},
+        subtitle = {mudança social no Brasil},
+        journal  = {Tempo Social},
+        year     = {2001},
+        pages    = {201--226},
+        doi      = {https://doi.org/10.1590/S0103-20702001000200010}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let item = item(&bibtex.bibliographies()[0]);
+
+    assert_eq!(item.type_().as_deref(), Some("article-journal"));
+    assert_eq!(
+        item.0["title"],
+        Value::String("Os sertões: mudança social no Brasil".into())
+    );
+    assert_eq!(item.0["page"], Value::String("201–226".into()));
+    assert_eq!(
+        item.0["DOI"],
+        Value::String("10.1590/S0103-20702001000200010".into())
+    );
+    assert_eq!(item.0["issued"], date(vec![2001]));
+    assert_eq!(
+        item.0["author"],
+        Value::Names(vec![
+            name("Rezende, Maria José de"),
+            NameValue::Literal(LiteralName {
+                literal: "Instituto Histórico".into()
+            }),
+        ])
+    );
+}
@@ -0,0 +1,168 @@
+use std::fmt::Display;
+
+use hayagriva::{
+    archive,
+    citationberg::{json::Item, IndependentStyle, Locale, LocaleFile, Style},
+    BibliographyDriver, BibliographyRequest, BufWriteFormat, CitationItem, CitationRequest,
+};
+use nom_bibtex::Bibliography;
+
+mod json;
+
+/// A style read from a Citation Style Language file, together with the
+/// locales its terms and dates are taken from.
+pub struct Csl {
+    style: IndependentStyle,
+    locales: Vec<Locale>,
+}
+
+/// A reference already rendered by the CSL processor.
+pub struct Rendered(String);
+
+impl Csl {
+    /// Parses a `.csl` style. Dependent styles are not supported since they
+    /// only point to another independent style.
+    pub fn from_xml(xml: &str) -> Self {
+        let style = match Style::from_xml(xml).expect("valid csl style") {
+            Style::Independent(style) => style,
+            Style::Dependent(_) => panic!("dependent csl styles are not supported"),
+        };
+
+        Csl {
+            style,
+            locales: archive::locales(),
+        }
+    }
+
+    /// Adds a CSL locale file, which takes precedence over the bundled
+    /// locales for the same language.
+    pub fn add_locale(&mut self, xml: &str) {
+        let locale = LocaleFile::from_xml(xml).expect("valid csl locale");
+
+        self.locales.insert(0, locale.into());
+    }
+
+    /// Renders the bibliography, in the order given by the style or, if it
+    /// does not sort, in the order of `bibs`.
+    pub fn render(&self, bibs: &[&Bibliography]) -> Vec<Rendered> {
+        let items: Vec<Item> = bibs.iter().map(|b| json::item(b)).collect();
+
+        let mut driver = BibliographyDriver::new();
+
+        for item in &items {
+            driver.citation(CitationRequest::new(
+                vec![CitationItem::with_entry(item)],
+                &self.style,
+                None,
+                &self.locales,
+                None,
+            ));
+        }
+
+        let rendered = driver.finish(BibliographyRequest::new(&self.style, None, &self.locales));
+
+        let bibliography = match rendered.bibliography {
+            Some(bibliography) => bibliography,
+            None => panic!("the csl style has no bibliography"),
+        };
+
+        bibliography
+            .items
+            .into_iter()
+            .map(|item| {
+                let mut buf = String::new();
+
+                if let Some(first) = item.first_field {
+                    first
+                        .write_buf(&mut buf, BufWriteFormat::Html)
+                        .expect("write to string");
+                    buf.push(' ');
+                }
+
+                item.content
+                    .write_buf(&mut buf, BufWriteFormat::Html)
+                    .expect("write to string");
+
+                Rendered(buf)
+            })
+            .collect()
+    }
+}
+
+impl Display for Rendered {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+const AUTHOR_DATE: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<style xmlns="http://purl.org/net/xbiblio/csl" class="in-text" version="1.0" default-locale="pt-BR">
+  <info>
+    <title>Test</title>
+    <id>test</id>
+    <updated>2022-01-01T00:00:00+00:00</updated>
+  </info>
+  <citation>
+    <layout>
+      <text variable="title"/>
+    </layout>
+  </citation>
+  <bibliography>
+    <sort>
+      <key variable="author"/>
+    </sort>
+    <layout suffix=".">
+      <group delimiter=". ">
+        <names variable="author">
+          <name name-as-sort-order="all" sort-separator=", " initialize-with=". " delimiter="; ">
+            <name-part name="family" text-case="uppercase"/>
+          </name>
+        </names>
+        <text variable="title" font-weight="bold"/>
+        <group delimiter=", ">
+          <text variable="publisher"/>
+          <date variable="issued">
+            <date-part name="year"/>
+          </date>
+        </group>
+      </group>
+    </layout>
+  </bibliography>
+</style>"#;
+
+#[test]
+fn render_style() {
+    let entries = r"
+    @book{Lapa1980,
+        author    = {Lapa, José Roberto Amaral},
+        title     = {O antigo sistema colonial},
+        publisher = {Brasiliense},
+        year      = {1980}
+    }
+    @book{Dias2011,
+        author    = {Dias, Thiago Alves},
+        title     = {Dinâmicas mercantis coloniais},
+        publisher = {UFRN},
+        year      = {2011}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entries).expect("valid bibtex");
+
+    let bibs: Vec<_> = bibtex.bibliographies().iter().collect();
+
+    let rendered: Vec<_> = Csl::from_xml(AUTHOR_DATE)
+        .render(&bibs)
+        .iter()
+        .map(|r| r.to_string())
+        .collect();
+
+    assert_eq!(
+        rendered,
+        [
+            "DIAS, T. A. <span style=\"font-weight: bold;\">Dinâmicas mercantis coloniais</span>. UFRN, 2011.",
+            "LAPA, J. R. A. <span style=\"font-weight: bold;\">O antigo sistema colonial</span>. Brasiliense, 1980.",
+        ]
+    );
+}
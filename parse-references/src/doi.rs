@@ -0,0 +1,167 @@
+use std::fmt::Display;
+
+/// Prefixes DOIs are commonly written with, compared without case.
+const PREFIXES: [&str; 9] = [
+    "https://doi.org/",
+    "http://doi.org/",
+    "https://dx.doi.org/",
+    "http://dx.doi.org/",
+    "doi.org/",
+    "dx.doi.org/",
+    "info:doi/",
+    "doi:",
+    "doi ",
+];
+
+/// The resolver of the canonical links.
+const RESOLVER: &str = "https://doi.org/";
+
+/// A DOI name such as `10.1590/S0103-20702001000200010`, without prefix and
+/// percent-encoding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Doi(String);
+
+impl Doi {
+    /// Accepts a bare DOI or one written as `doi:10.1000/xyz` or as a
+    /// `doi.org` link, possibly percent-encoded and followed by punctuation.
+    /// `None` unless it has a `10.NNNN/` prefix and a suffix.
+    pub fn parse(s: &str) -> Option<Doi> {
+        let mut s = s.trim();
+
+        for prefix in PREFIXES {
+            if s.len() >= prefix.len()
+                && s.is_char_boundary(prefix.len())
+                && s[..prefix.len()].eq_ignore_ascii_case(prefix)
+            {
+                s = s[prefix.len()..].trim_start();
+                break;
+            }
+        }
+
+        let name = strip_trailing_punctuation(&percent_decode(s)).to_string();
+
+        let (prefix, suffix) = name.split_once('/')?;
+        let registrant = prefix.strip_prefix("10.")?;
+
+        let valid_registrant = registrant
+            .split('.')
+            .all(|code| !code.is_empty() && code.chars().all(|c| c.is_ascii_digit()))
+            && registrant
+                .split('.')
+                .next()
+                .is_some_and(|code| code.len() >= 4);
+
+        if !valid_registrant || suffix.is_empty() || suffix.contains(char::is_whitespace) {
+            return None;
+        }
+
+        Some(Doi(name))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
+    /// The `doi.org` link, with the characters that are not allowed in a URL
+    /// percent-encoded.
+    pub fn url(&self) -> String {
+        let mut url = String::from(RESOLVER);
+
+        for c in self.0.chars() {
+            if c.is_ascii_alphanumeric() || "-._~!$&'()*+,;=:@/".contains(c) {
+                url.push(c);
+            } else {
+                let mut buf = [0; 4];
+
+                for byte in c.encode_utf8(&mut buf).bytes() {
+                    url.push_str(&format!("%{:02X}", byte));
+                }
+            }
+        }
+
+        url
+    }
+}
+
+impl Display for Doi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .filter(|_| bytes[i] == b'%')
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+
+        match hex {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Drops the punctuation that ends the sentence a DOI was copied from,
+/// keeping closing brackets that are part of it: `10.1002/(SICI)1097`.
+fn strip_trailing_punctuation(mut s: &str) -> &str {
+    loop {
+        let unbalanced =
+            |open, close| s.ends_with(close) && s.matches(open).count() < s.matches(close).count();
+
+        if s.ends_with(['.', ',', ';', ':']) || unbalanced('(', ')') || unbalanced('[', ']') {
+            s = &s[..s.len() - 1];
+        } else {
+            return s;
+        }
+    }
+}
+
+#[test]
+fn prefixes() {
+    let expected = Some(Doi("10.1000/xyz".to_string()));
+
+    assert_eq!(Doi::parse("10.1000/xyz"), expected);
+    assert_eq!(Doi::parse("doi:10.1000/xyz"), expected);
+    assert_eq!(Doi::parse("DOI: 10.1000/xyz"), expected);
+    assert_eq!(Doi::parse("https://dx.doi.org/10.1000/xyz"), expected);
+    assert_eq!(Doi::parse("http://doi.org/10.1000/xyz."), expected);
+}
+
+#[test]
+fn encoded() {
+    let doi =
+        Doi::parse("https://doi.org/10.1002/%28SICI%291097-4571%28199806%2949%3A8%3C693").unwrap();
+
+    assert_eq!(doi.name(), "10.1002/(SICI)1097-4571(199806)49:8<693");
+    assert_eq!(
+        doi.url(),
+        "https://doi.org/10.1002/(SICI)1097-4571(199806)49:8%3C693"
+    );
+    assert_eq!(
+        Doi::parse("10.1002/(SICI)1097).").map(|d| d.0),
+        Some("10.1002/(SICI)1097".to_string())
+    );
+}
+
+#[test]
+fn invalid() {
+    assert_eq!(Doi::parse("10.10/xyz"), None);
+    assert_eq!(Doi::parse("11.1000/xyz"), None);
+    assert_eq!(Doi::parse("10.1000/"), None);
+    assert_eq!(Doi::parse("https://example.com/10.1000/xyz"), None);
+}
@@ -0,0 +1,132 @@
+use std::{cell::RefCell, fmt::Display};
+
+use crate::doi::Doi;
+
+/// Attributes added to every link and how DOIs are labeled.
+#[derive(Clone, Default)]
+pub struct LinkOptions {
+    pub rel: Option<String>,
+    pub target: Option<String>,
+    pub class: Option<String>,
+    /// Label DOIs as `doi:10.1000/xyz` instead of their full URL.
+    pub short_doi: bool,
+}
+
+thread_local! {
+    static LINK_OPTIONS: RefCell<LinkOptions> = RefCell::new(LinkOptions::default());
+}
+
+pub fn set_link_options(options: LinkOptions) {
+    LINK_OPTIONS.with(|o| *o.borrow_mut() = options);
+}
+
+/// Text with the characters that are special in HTML escaped.
+pub struct Escaped<'e>(pub &'e str);
+
+impl<'e> Display for Escaped<'e> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '"' => f.write_str("&quot;")?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// An `<a href>` to `href` labeled with `text`.
+pub struct Anchor<'a> {
+    pub href: &'a str,
+    pub text: &'a str,
+}
+
+impl<'a> Anchor<'a> {
+    /// A link labeled with its own URL.
+    pub fn url(url: &'a str) -> Self {
+        Anchor {
+            href: url,
+            text: url,
+        }
+    }
+}
+
+impl<'a> Display for Anchor<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, r#"<a href="{}""#, Escaped(self.href))?;
+
+        LINK_OPTIONS.with(|o| {
+            let o = o.borrow();
+
+            let attributes = [("rel", &o.rel), ("target", &o.target), ("class", &o.class)];
+
+            for (name, value) in attributes {
+                if let Some(value) = value {
+                    write!(f, r#" {}="{}""#, name, Escaped(value))?;
+                }
+            }
+
+            Ok(())
+        })?;
+
+        write!(f, ">{}</a>", Escaped(self.text))
+    }
+}
+
+/// The link to a DOI, labeled with the URL or with `doi:` and the DOI name.
+pub struct DoiAnchor<'d>(pub &'d Doi);
+
+impl<'d> Display for DoiAnchor<'d> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let url = self.0.url();
+
+        if LINK_OPTIONS.with(|o| o.borrow().short_doi) {
+            let text = format!("doi:{}", self.0.name());
+
+            write!(
+                f,
+                "{}",
+                Anchor {
+                    href: &url,
+                    text: &text
+                }
+            )
+        } else {
+            write!(f, "{}", Anchor::url(&url))
+        }
+    }
+}
+
+#[test]
+fn anchor() {
+    let anchor = Anchor::url("https://example.com/?a=1&b=2");
+
+    assert_eq!(
+        format!("{anchor}"),
+        r#"<a href="https://example.com/?a=1&amp;b=2">https://example.com/?a=1&amp;b=2</a>"#,
+    );
+}
+
+#[test]
+fn link_options() {
+    set_link_options(LinkOptions {
+        rel: Some("noopener".to_string()),
+        target: Some("_blank".to_string()),
+        class: None,
+        short_doi: true,
+    });
+
+    let doi = Doi::parse("10.1000/xyz").unwrap();
+    let anchor = format!("{}", DoiAnchor(&doi));
+
+    set_link_options(LinkOptions::default());
+
+    assert_eq!(
+        anchor,
+        r#"<a href="https://doi.org/10.1000/xyz" rel="noopener" target="_blank">doi:10.1000/xyz</a>"#,
+    );
+}
@@ -0,0 +1,155 @@
+use nom_bibtex::{model::StringValueType, Bibliography, Entry};
+
+const MONTHS: [&[&str]; 12] = [
+    &["jan", "january", "janeiro"],
+    &["feb", "february", "fev", "fevereiro"],
+    &["mar", "march", "março", "marco"],
+    &["apr", "april", "abr", "abril"],
+    &["may", "mai", "maio"],
+    &["jun", "june", "junho"],
+    &["jul", "july", "julho"],
+    &["aug", "august", "ago", "agosto"],
+    &["sep", "sept", "september", "set", "setembro"],
+    &["oct", "october", "out", "outubro"],
+    &["nov", "november", "novembro"],
+    &["dec", "december", "dez", "dezembro"],
+];
+
+/// The month number of an English or Portuguese month name or abbreviation.
+pub fn month_number(name: &str) -> Option<u8> {
+    let name = name.trim().trim_end_matches('.').to_lowercase();
+
+    MONTHS
+        .iter()
+        .position(|names| names.contains(&name.as_str()))
+        .map(|i| i as u8 + 1)
+}
+
+/// `@string` macros defined by the user, either in separate files or in the
+/// bibliography itself. Month abbreviations that are not defined resolve to
+/// the month number.
+#[derive(Default)]
+pub struct Macros {
+    values: Vec<(String, String)>,
+}
+
+impl Macros {
+    /// Adds the `@string` definitions among `entries`. A macro defined again
+    /// replaces the previous definition.
+    pub fn define(&mut self, entries: &[Entry]) {
+        for entry in entries {
+            if let Entry::Variable(var) = entry {
+                let value = self.expand(&var.key, &var.value);
+
+                match self.values.iter_mut().find(|(k, _)| *k == var.key) {
+                    Some((_, previous)) => {
+                        eprintln!("warning: @string {} is defined more than once", var.key);
+                        *previous = value;
+                    }
+                    None => self.values.push((var.key.clone(), value)),
+                }
+            }
+        }
+    }
+
+    /// Expands the macros in `value`. Undefined macros are kept by name.
+    fn expand(&self, key: &str, value: &[StringValueType]) -> String {
+        let mut result = String::new();
+
+        for chunk in value {
+            match chunk {
+                StringValueType::Str(s) => result.push_str(s),
+                StringValueType::Abbreviation(name) => {
+                    if let Some((_, v)) = self.values.iter().find(|(k, _)| k == name) {
+                        result.push_str(v);
+                    } else if let Some(month) = month_number(name) {
+                        result.push_str(&month.to_string());
+                    } else {
+                        eprintln!("warning: undefined @string {} in {}", name, key);
+                        result.push_str(name);
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    /// The bibliography entries with their macros expanded.
+    pub fn bibliographies(&self, entries: Vec<Entry>) -> Vec<Bibliography> {
+        entries
+            .into_iter()
+            .filter_map(|entry| match entry {
+                Entry::Bibliography(entry_type, citation_key, tags) => {
+                    let tags = tags
+                        .into_iter()
+                        .map(|tag| {
+                            let mut value = self.expand(&tag.key, &tag.value);
+
+                            if tag.key == "month" {
+                                if let Some(month) = month_number(&value) {
+                                    value = month.to_string();
+                                }
+                            }
+
+                            (tag.key, value)
+                        })
+                        .collect();
+
+                    Some(Bibliography::new(entry_type, citation_key, tags))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+fn parse(sources: &[&str]) -> Vec<Bibliography> {
+    let mut macros = Macros::default();
+    let mut entries = Vec::new();
+
+    for source in sources {
+        let raw = nom_bibtex::Bibtex::raw_parse(source).expect("valid bibtex");
+        macros.define(&raw);
+        entries = raw;
+    }
+
+    macros.bibliographies(entries)
+}
+
+#[test]
+fn native_months() {
+    let bibs = parse(&[r"
+    @article{A, month = jul, year = 2001}
+    @article{B, month = dez}
+    @article{C, month = {Setembro}}
+    "]);
+
+    let months: Vec<_> = bibs.iter().map(|b| b.tags()[0].1.as_str()).collect();
+
+    assert_eq!(months, ["7", "12", "9"]);
+}
+
+#[test]
+fn user_macros() {
+    let bibs = parse(&[
+        r#"@string{ihgrn = "Instituto Histórico e Geográfico do RN"}"#,
+        r#"
+        @string{jul = "julho"}
+        @string{jul = "July"}
+        @book{A, publisher = ihgrn, note = jul # " 2001"}
+        "#,
+    ]);
+
+    assert_eq!(
+        bibs[0].tags(),
+        [
+            (
+                "publisher".to_string(),
+                "Instituto Histórico e Geográfico do RN".to_string()
+            ),
+            ("note".to_string(), "July 2001".to_string()),
+        ]
+    );
+}
@@ -1,10 +1,31 @@
-use std::{fmt::Write, io::Read};
+use std::{
+    fmt::{Display, Write},
+    io::Read,
+};
 
 use nom_bibtex::Bibtex;
 
-use crate::abnt::Abnt;
+use crate::{
+    abnt::{
+        locale::{self, Lang},
+        Abnt,
+    },
+    apa::Apa,
+    citations::{Citations, Marker},
+    csl::Csl,
+    html::LinkOptions,
+    macros::Macros,
+    numeric::{Ieee, Label, Numbered, Vancouver},
+};
 
 mod abnt;
+mod apa;
+mod citations;
+mod csl;
+mod doi;
+mod html;
+mod macros;
+mod numeric;
 
 pub struct MissingTags {
     pub missing_tags: Vec<String>,
@@ -13,6 +34,11 @@ pub struct MissingTags {
 struct FixPunctuation<W> {
     write: W,
     last_char_type: CharType,
+    /// Pairs of punctuation the style writes on purpose, such as the comma
+    /// after an initial in APA (`Rezende, M. J., & Souza, A.`), or the colon
+    /// of an unknown place (`[s.l.: s.n.]`) and the comma after an ordinal
+    /// (`ENCONTRO, 17., 2016`) in ABNT.
+    kept: &'static [(char, char)],
 }
 
 enum CharType {
@@ -33,7 +59,19 @@ impl<W: std::io::Write> Write for FixPunctuation<W> {
     fn write_str(&mut self, s: &str) -> std::fmt::Result {
         for c in s.chars() {
             let char_type = CharType::of(c);
+            let kept = match (&self.last_char_type, &char_type) {
+                (CharType::Punctuation(last), CharType::Punctuation(c)) => {
+                    self.kept.contains(&(*last, *c))
+                }
+                _ => false,
+            };
             match (&self.last_char_type, &char_type) {
+                _ if kept => {
+                    let mut buf = [0; 4];
+                    self.write
+                        .write(c.encode_utf8(&mut buf).as_bytes())
+                        .map_err(|_| std::fmt::Error)?;
+                }
                 (CharType::Punctuation('?'), CharType::Punctuation('?'))
                 | (CharType::Punctuation('?'), CharType::Punctuation('!'))
                 | (CharType::Punctuation('!'), CharType::Punctuation('?'))
@@ -63,53 +101,277 @@ impl<W: std::io::Write> Write for FixPunctuation<W> {
     }
 }
 
+#[derive(Clone, Copy)]
+enum Style {
+    Abnt,
+    Apa,
+    Vancouver,
+    Ieee,
+}
+
+impl Style {
+    /// Punctuation pairs that `FixPunctuation` must not collapse.
+    fn kept_punctuation(self) -> &'static [(char, char)] {
+        match self {
+            Style::Abnt => &[('.', ':'), ('.', ',')],
+            Style::Apa => &[('.', ',')],
+            _ => &[],
+        }
+    }
+}
+
+struct Options {
+    style: Style,
+    /// Number references by citation order instead of sorting them.
+    numeric: bool,
+    /// Article scanned for the citation order, whose markers are replaced by
+    /// the reference numbers.
+    article: Option<String>,
+    /// Where the article with numbered citations is written, instead of
+    /// before the reference list on stdout. Never the article itself.
+    output: Option<String>,
+    /// Citation Style Language file used instead of the built-in styles.
+    csl: Option<String>,
+    /// CSL locale file with the terms of the style's language.
+    csl_locale: Option<String>,
+    /// Language of entries without a `langid`.
+    lang: Lang,
+    /// Sort works by their original date (`origdate`).
+    sort_origdate: bool,
+    /// Files with `@string` macros used by the bibliography.
+    strings: Vec<String>,
+    /// Attributes of the links to URLs and DOIs.
+    links: LinkOptions,
+}
+
+impl Options {
+    fn from_args() -> Options {
+        let mut options = Options {
+            style: Style::Abnt,
+            numeric: false,
+            article: None,
+            output: None,
+            csl: None,
+            csl_locale: None,
+            lang: Lang::PtBr,
+            sort_origdate: false,
+            strings: Vec::new(),
+            links: LinkOptions::default(),
+        };
+        let mut args = std::env::args().skip(1);
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--style" => {
+                    options.style = match args.next().as_deref() {
+                        Some("abnt") => Style::Abnt,
+                        Some("apa") => Style::Apa,
+                        Some("vancouver") => Style::Vancouver,
+                        Some("ieee") => Style::Ieee,
+                        s => panic!(
+                            "unknown style: {:?}, expecting abnt, apa, vancouver or ieee",
+                            s
+                        ),
+                    }
+                }
+                "--numeric" => options.numeric = true,
+                "--article" => options.article = Some(args.next().expect("article path")),
+                "--output" => options.output = Some(args.next().expect("output path")),
+                "--lang" => {
+                    let lang = args.next();
+                    options.lang = match lang.as_deref().and_then(Lang::from_tag) {
+                        Some(lang) => lang,
+                        None => panic!("unknown language: {:?}, expecting pt-BR, en or es", lang),
+                    }
+                }
+                "--sort-origdate" => options.sort_origdate = true,
+                "--strings" => options
+                    .strings
+                    .push(args.next().expect("@string file path")),
+                "--link-rel" => options.links.rel = Some(args.next().expect("link rel")),
+                "--link-target" => options.links.target = Some(args.next().expect("link target")),
+                "--link-class" => options.links.class = Some(args.next().expect("link class")),
+                "--short-doi" => options.links.short_doi = true,
+                "--csl" => options.csl = Some(args.next().expect("csl style path")),
+                "--csl-locale" => options.csl_locale = Some(args.next().expect("csl locale path")),
+                _ => panic!("unexpected argument: {}", arg),
+            }
+        }
+
+        match options.style {
+            Style::Vancouver | Style::Ieee => options.numeric = true,
+            Style::Apa if options.numeric => panic!("apa is not a numeric style"),
+            _ => {}
+        }
+
+        if options.output.is_some() && options.output == options.article {
+            panic!("the output must not overwrite the article");
+        }
+
+        options
+    }
+}
+
 fn main() {
-    let mut buf = br#"
-    @string (jan = "1")
-    @string (feb = "2")
-    @string (mar = "3")
-    @string (apr = "4")
-    @string (may = "5")
-    @string (jun = "6")
-    @string (jul = "7")
-    @string (ago = "8")
-    @string (sep = "9")
-    @string (oct = "10")
-    @string (nov = "11")
-    @string (dec = "12")
-    @string (dez = "12")
-    "#
-    .to_vec();
+    let options = Options::from_args();
+
+    locale::set_default(options.lang);
+    abnt::sort_by_origdate(options.sort_origdate);
+    html::set_link_options(options.links.clone());
+
+    let mut macros = Macros::default();
+
+    for path in &options.strings {
+        let strings = std::fs::read_to_string(path).expect("to read the @string file");
+        macros.define(&Bibtex::raw_parse(&strings).expect("valid @string file"));
+    }
+
+    let mut buf = String::new();
     std::io::stdin()
-        .read_to_end(&mut buf)
-        .expect("to read stdin");
+        .read_to_string(&mut buf)
+        .expect("stdin is a valid utf8 string");
 
-    let buf = String::from_utf8(buf).expect("stdin is a valid utf8 string");
+    let entries = Bibtex::raw_parse(&buf).expect("valid bibtex");
+    macros.define(&entries);
 
-    let bibtex = Bibtex::parse(&buf).expect("valid bibtex");
+    let bibliographies = macros.bibliographies(entries);
 
-    let mut bibs: Vec<_> = bibtex
-        .bibliographies()
+    let bibs: Vec<_> = bibliographies
         .iter()
         .filter(|b| !b.citation_key().starts_with("Self"))
-        .map(Abnt)
         .collect();
 
+    for b in &bibs {
+        abnt::lint(b);
+    }
+
+    if let Some(path) = &options.csl {
+        let mut csl = Csl::from_xml(&std::fs::read_to_string(path).expect("to read the style"));
+
+        if let Some(path) = &options.csl_locale {
+            csl.add_locale(&std::fs::read_to_string(path).expect("to read the locale"));
+        }
+
+        // The style already punctuates its output, initials included.
+        print_list(
+            locale::terms().references,
+            &[('.', ','), ('.', ':')],
+            csl.render(&bibs).into_iter(),
+        );
+        return;
+    }
+
+    let kept = options.style.kept_punctuation();
+
+    if !options.numeric {
+        match options.style {
+            Style::Abnt => print_sorted(
+                locale::terms().references,
+                kept,
+                bibs.into_iter().map(Abnt).collect(),
+            ),
+            _ => print_sorted("References", kept, bibs.into_iter().map(Apa).collect()),
+        }
+        return;
+    }
+
+    let article = options
+        .article
+        .as_ref()
+        .map(|path| std::fs::read_to_string(path).expect("to read the article"));
+
+    let scanned = if let Some(article) = &article {
+        Citations::scan(article)
+    } else {
+        let mut citations = Citations::default();
+        for b in &bibs {
+            citations.push(b.citation_key());
+        }
+        citations
+    };
+
+    for b in &bibs {
+        if scanned.number(b.citation_key()).is_none() {
+            eprintln!("warning: {} is never cited", b.citation_key());
+        }
+    }
+
+    // Only keys found in the bibliography are numbered, so that the list
+    // and the article agree and have no gaps.
+    let mut citations = Citations::default();
+
+    for key in scanned.keys() {
+        if bibs.iter().any(|b| b.citation_key() == key) {
+            citations.push(key);
+        } else {
+            eprintln!("warning: cited key {} not found in the bibliography", key);
+        }
+    }
+
+    let cited = citations.keys().enumerate().filter_map(|(i, key)| {
+        bibs.iter()
+            .find(|b| b.citation_key() == key)
+            .map(|b| (i + 1, *b))
+    });
+
+    let (title, label, marker) = match options.style {
+        Style::Ieee => ("References", Label::Brackets, Marker::Brackets),
+        Style::Abnt => (
+            locale::terms().references,
+            Label::Plain,
+            Marker::Parentheses,
+        ),
+        _ => ("References", Label::Period, Marker::Parentheses),
+    };
+
+    if let Some(article) = &article {
+        let article = citations.replace(article, marker);
+
+        match &options.output {
+            Some(path) => std::fs::write(path, article).expect("to write the output"),
+            None => print!("{}", article),
+        }
+    }
+
+    let numbered = |(number, b)| Numbered {
+        number,
+        label,
+        reference: b,
+    };
+
+    match options.style {
+        Style::Abnt => print_list(title, kept, cited.map(numbered).map(|n| n.map(Abnt))),
+        Style::Vancouver => print_list(title, kept, cited.map(numbered).map(|n| n.map(Vancouver))),
+        _ => print_list(title, kept, cited.map(numbered).map(|n| n.map(Ieee))),
+    }
+}
+
+fn print_sorted<R: Ord + Display>(title: &str, kept: &'static [(char, char)], mut bibs: Vec<R>) {
     bibs.sort_unstable();
 
+    print_list(title, kept, bibs.into_iter())
+}
+
+fn print_list<R: Display>(
+    title: &str,
+    kept: &'static [(char, char)],
+    bibs: impl Iterator<Item = R>,
+) {
     println!(
         r#"
 
 <div class="references txt-sml txt-left proportional-nums">
 
-## Referências
+## {}
 
-"#
+"#,
+        title
     );
 
     let mut out = FixPunctuation {
         write: std::io::stdout(),
         last_char_type: CharType::Other,
+        kept,
     };
 
     for bib in bibs {
@@ -118,3 +380,104 @@ fn main() {
 
     println!("</div>");
 }
+
+#[cfg(test)]
+fn fix_punctuation(kept: &'static [(char, char)], s: &str) -> String {
+    let mut out = FixPunctuation {
+        write: Vec::new(),
+        last_char_type: CharType::Other,
+        kept,
+    };
+
+    out.write_str(s).unwrap();
+
+    String::from_utf8(out.write).unwrap()
+}
+
+#[test]
+fn initials_before_comma() {
+    let s = "Rezende, M. J., & Souza, A. (2001).";
+
+    assert_eq!(
+        fix_punctuation(Style::Apa.kept_punctuation(), s),
+        "Rezende, M. J., & Souza, A. (2001).",
+    );
+    assert_eq!(
+        fix_punctuation(Style::Vancouver.kept_punctuation(), "Ed. Nacional., 2001."),
+        "Ed. Nacional. 2001.",
+    );
+}
+
+#[test]
+fn event_ordinal() {
+    let entry = r"
+    @inproceedings{EcMOTTERTelenovela,
+        author     = {Motter, Maria de Lourdes},
+        title      = {Telenovela: reflexo e refração na arte do cotidiano},
+        eventtitle = {Congresso Brasileiro de Ciências da Comunicação},
+        number     = {21},
+        venue      = {Recife},
+        year       = {1998}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        fix_punctuation(Style::Abnt.kept_punctuation(), &Abnt(bib).to_string()),
+        "MOTTER, M. d. L. Telenovela: reflexo e refração na arte do cotidiano. In: CONGRESSO BRASILEIRO DE CIÊNCIAS DA COMUNICAÇÃO, 21., 1998, Recife.",
+    );
+}
+
+#[test]
+fn unknown_place_and_publisher() {
+    let s = "PASSOS, A. B. [s.l.: s.n.], 1854.";
+
+    assert_eq!(fix_punctuation(Style::Abnt.kept_punctuation(), s), s);
+    assert_eq!(
+        fix_punctuation(Style::Ieee.kept_punctuation(), "Ed.: Natal."),
+        "Ed. Natal.",
+    );
+}
+
+#[test]
+fn proceedings_event() {
+    let entries = r"
+    @proceedings{EEH2016,
+        eventtitle = {Encontro Estadual de História},
+        number     = {17},
+        venue      = {Guarabira},
+        title      = {Anais},
+        location   = {Guarabira},
+        publisher  = {ANPUH-PB},
+        year       = {2016}
+    }
+    @inproceedings{Dias2016,
+        author     = {Dias, P. O.},
+        title      = {Gentes de conquista},
+        eventtitle = {Encontro Estadual de História},
+        number     = {17},
+        venue      = {Guarabira},
+        booktitle  = {Anais},
+        location   = {Guarabira},
+        publisher  = {ANPUH-PB},
+        year       = {2016},
+        pages      = {1--15}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entries).expect("valid bibtex");
+
+    let bibs = bibtex.bibliographies();
+
+    assert_eq!(
+        fix_punctuation(Style::Abnt.kept_punctuation(), &Abnt(&bibs[0]).to_string()),
+        "ENCONTRO ESTADUAL DE HISTÓRIA, 17., 2016, Guarabira. <strong>Anais</strong> [...]. Guarabira: ANPUH-PB, 2016.",
+    );
+    assert_eq!(
+        fix_punctuation(Style::Abnt.kept_punctuation(), &Abnt(&bibs[1]).to_string()),
+        "DIAS, P. O. Gentes de conquista. In: ENCONTRO ESTADUAL DE HISTÓRIA, 17., 2016, Guarabira. <strong>Anais</strong> [...]. Guarabira: ANPUH-PB, 2016. p. 1–15.",
+    );
+}
@@ -0,0 +1,477 @@
+use std::fmt::{Display, Error, Formatter, Result};
+
+use nom_bibtex::Bibliography;
+
+use crate::{
+    abnt::{
+        authors::{split_name, Initials},
+        join::JoinAnd,
+        tex, AND_REGEX,
+    },
+    apa::{
+        date::Date,
+        emphasis::Em,
+        parts::{Edition, LabeledPages},
+    },
+    html::{Anchor, DoiAnchor},
+};
+
+use super::Fields;
+
+/// IEEE lists up to six authors; longer lists keep only the first one.
+const MAX_AUTHORS: usize = 6;
+
+const MONTHS: [&str; 12] = [
+    "Jan.", "Feb.", "Mar.", "Apr.", "May", "Jun.", "Jul.", "Aug.", "Sep.", "Oct.", "Nov.", "Dec.",
+];
+
+/// A bibliography entry rendered according to the IEEE reference style.
+#[repr(transparent)]
+pub struct Ieee<'b>(pub &'b Bibliography);
+
+/// `M. J. Rezende, A. Souza, and P. Assunção`.
+struct Names<'a>(&'a str);
+
+/// `M. J. Rezende`.
+struct Name<'a>(&'a str);
+
+/// `Jul. 2019` or `Jul. 19, 2019`.
+struct IeeeDate<'d>(&'d Date<'d>);
+
+/// A title between quotes, with the following comma inside them unless
+/// the title already ends in punctuation: `“Title,”`.
+struct Quoted<'t>(&'t str, Option<&'t str>, char);
+
+impl<'a> Display for Names<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let names: Vec<_> = AND_REGEX.split(self.0).map(str::trim).collect();
+
+        match names.len() {
+            n if n > MAX_AUTHORS => write!(f, "{} <em>et al.</em>", Name(names[0])),
+            2 => write!(f, "{} and {}", Name(names[0]), Name(names[1])),
+            _ => write!(
+                f,
+                "{}",
+                JoinAnd::new(", ", ", and ", names.into_iter().map(Name))
+            ),
+        }
+    }
+}
+
+impl<'a> Display for Name<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match split_name(self.0) {
+            Some((family, given)) => write!(
+                f,
+                "{} {}",
+                Initials(given.trim()),
+                tex::NoBraces(family.trim())
+            ),
+            None => write!(f, "{}", tex::NoBraces(self.0)),
+        }
+    }
+}
+
+impl<'d> Display for IeeeDate<'d> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let month = self
+            .0
+            .month
+            .filter(|m| (1..=12).contains(m))
+            .map(|m| MONTHS[(m - 1) as usize]);
+
+        match (self.0.year, month, self.0.day) {
+            (Some(year), Some(month), Some(day)) => write!(f, "{month} {day}, {year}"),
+            (Some(year), Some(month), None) => write!(f, "{month} {year}"),
+            (Some(year), None, _) => write!(f, "{year}"),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'t> Display for Quoted<'t> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut title = format!("{}", tex::Text(self.0));
+
+        if let Some(subtitle) = self.1 {
+            title = format!("{}: {}", title, tex::Text(subtitle));
+        }
+
+        if !title.ends_with(['.', '?', '!']) {
+            title.push(self.2);
+        }
+
+        write!(f, "“{title}”")
+    }
+}
+
+fn write_title(f: &mut Formatter<'_>, title: &str, subtitle: Option<&str>) -> Result {
+    write!(f, "{}", tex::Text(title))?;
+
+    if let Some(subtitle) = subtitle {
+        write!(f, ": {}", tex::Text(subtitle))?;
+    }
+
+    Ok(())
+}
+
+fn write_authors(f: &mut Formatter<'_>, b: &Fields) -> Result {
+    if !b.author.is_empty() {
+        write!(f, "{}, ", Names(b.author))
+    } else if !b.editor.is_empty() {
+        write!(f, "{}, {}, ", Names(b.editor), editors(b.editor))
+    } else {
+        Ok(())
+    }
+}
+
+fn editors(names: &str) -> &'static str {
+    if AND_REGEX.is_match(names) {
+        "Eds."
+    } else {
+        "Ed."
+    }
+}
+
+fn write_publication(f: &mut Formatter<'_>, b: &Fields) -> Result {
+    if !b.location.is_empty() {
+        write!(f, "{}: ", tex::Text(b.location))?;
+    }
+
+    if !b.publisher.is_empty() {
+        write!(f, "{}, ", tex::Text(b.publisher))?;
+    }
+
+    write!(f, "{}", IeeeDate(&b.date))
+}
+
+/// Writes the edition after a comma, telling whether it already ends with a
+/// period, as `2nd ed.` does.
+fn write_edition(f: &mut Formatter<'_>, b: &Fields) -> std::result::Result<bool, Error> {
+    let Some(edition) = b.edition else {
+        return Ok(false);
+    };
+
+    let edition = Edition(edition).to_string();
+    write!(f, ", {}", edition)?;
+
+    Ok(edition.ends_with('.'))
+}
+
+fn write_doi(f: &mut Formatter<'_>, b: &Fields) -> Result {
+    if let Some(doi) = &b.doi {
+        write!(f, ", {}", DoiAnchor(doi))?;
+    }
+
+    Ok(())
+}
+
+fn article(f: &mut Formatter<'_>, b: &Fields) -> Result {
+    write_authors(f, b)?;
+    write!(f, "{}", Quoted(b.title, b.subtitle, ','))?;
+
+    if let Some(journal) = b.short_journal.or(b.journal) {
+        write!(f, " {}", Em(tex::Text(journal)))?;
+    }
+
+    if let Some(volume) = b.volume {
+        write!(f, ", vol. {volume}")?;
+    }
+
+    if let Some(issue) = b.issue {
+        write!(f, ", no. {issue}")?;
+    }
+
+    if let Some(pages) = &b.pages {
+        write!(f, ", {}", LabeledPages(pages))?;
+    }
+
+    write!(f, ", {}", IeeeDate(&b.date))?;
+    write_doi(f, b)?;
+
+    f.write_str(".")
+}
+
+fn book(f: &mut Formatter<'_>, b: &Fields) -> Result {
+    write_authors(f, b)?;
+
+    let mut title = format!("{}", tex::Text(b.title));
+
+    if let Some(subtitle) = b.subtitle {
+        title = format!("{}: {}", title, tex::Text(subtitle));
+    }
+
+    write!(f, "{}", Em(title))?;
+
+    if write_edition(f, b)? {
+        f.write_str(" ")?;
+    } else {
+        f.write_str(". ")?;
+    }
+
+    write_publication(f, b)?;
+    write_doi(f, b)?;
+
+    f.write_str(".")
+}
+
+fn chapter(f: &mut Formatter<'_>, b: &Fields) -> Result {
+    if !b.author.is_empty() {
+        write!(f, "{}, ", Names(b.author))?;
+    }
+
+    write!(f, "{} in <em>", Quoted(b.title, b.subtitle, ','))?;
+    write_title(f, b.booktitle, b.booksubtitle)?;
+    f.write_str("</em>")?;
+
+    let edition_period = write_edition(f, b)?;
+
+    if !b.bookauthor.is_empty() {
+        write!(f, ", {}. ", Names(b.bookauthor))?;
+    } else if !b.editor.is_empty() {
+        write!(f, ", {}, {} ", Names(b.editor), editors(b.editor))?;
+    } else if edition_period {
+        f.write_str(" ")?;
+    } else {
+        f.write_str(". ")?;
+    }
+
+    write_publication(f, b)?;
+
+    if let Some(pages) = &b.pages {
+        write!(f, ", {}", LabeledPages(pages))?;
+    }
+
+    write_doi(f, b)?;
+
+    f.write_str(".")
+}
+
+fn thesis(f: &mut Formatter<'_>, b: &Fields) -> Result {
+    let thesis_type = match b.thesis_type {
+        "phdthesis" => "Ph.D. dissertation",
+        "mathesis" | "mastersthesis" => "M.S. thesis",
+        "thesis" => "Thesis",
+        t => t,
+    };
+
+    write_authors(f, b)?;
+    write!(
+        f,
+        "{} {}",
+        Quoted(b.title, b.subtitle, ','),
+        tex::Text(thesis_type)
+    )?;
+
+    for part in [b.institution, b.location] {
+        if !part.is_empty() {
+            write!(f, ", {}", tex::Text(part))?;
+        }
+    }
+
+    write!(f, ", {}.", IeeeDate(&b.date))
+}
+
+fn online(f: &mut Formatter<'_>, b: &Fields) -> Result {
+    if !b.author.is_empty() {
+        write!(f, "{}, ", Names(b.author))?;
+    } else if let Some(site) = b.site {
+        write!(f, "{}, ", tex::Text(site))?;
+    }
+
+    write!(f, "{}", Quoted(b.title, b.subtitle, '.'))?;
+
+    if b.date.year.is_some() {
+        write!(f, " {}.", IeeeDate(&b.date))?;
+    }
+
+    if let Some(url) = b.url {
+        write!(f, " {}", Anchor::url(url))?;
+    }
+
+    if let Some(url_date) = b.url_date {
+        write!(f, " (accessed {}).", IeeeDate(&Date::parse(url_date)))?;
+    }
+
+    Ok(())
+}
+
+impl<'b> Display for Ieee<'b> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let fields = Fields::from_bib(self.0);
+
+        match self.0.entry_type() {
+            "article" => article(f, &fields),
+            "book" | "mvbook" | "collection" | "mvcollection" | "proceedings" | "reference"
+            | "mvreference" => book(f, &fields),
+            "inbook" | "incollection" | "inproceedings" | "inreference" => chapter(f, &fields),
+            "thesis" | "phdthesis" | "mastersthesis" => thesis(f, &fields),
+            // Other types, such as movies and periodicals, are written like a
+            // stand-alone web document.
+            _ => online(f, &fields),
+        }
+    }
+}
+
+#[test]
+fn names() {
+    assert_eq!(
+        format!("{}", Names("Rezende, M. J. and Souza, A.")),
+        "M. J. Rezende and A. Souza"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            Names("Fragoso, J. and Bicalho, M. F. and Gouvêa, M. F.")
+        ),
+        "J. Fragoso, M. F. Bicalho, and M. F. Gouvêa"
+    );
+}
+
+#[test]
+fn article_from_bib() {
+    let entry = r"
+    @article{Rezende2001,
+        author   = {Rezende, M. J.},
+        title    = {Os sertões e os (des)caminhos da mudança social no Brasil},
+        journal  = {Tempo Social},
+        volume   = {13},
+        number   = {2},
+        year     = {2001},
+        pages    = {201--226},
+        doi      = {10.1590/S0103-20702001000200010}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Ieee(bib)),
+        r#"M. J. Rezende, “Os sertões e os (des)caminhos da mudança social no Brasil,” <em>Tempo Social</em>, vol. 13, no. 2, pp. 201–226, 2001, <a href="https://doi.org/10.1590/S0103-20702001000200010">https://doi.org/10.1590/S0103-20702001000200010</a>."#,
+    );
+}
+
+#[test]
+fn incollection_from_bib() {
+    let entry = r"
+    @incollection{Alveal2019,
+        author       = {Alveal, C. M. O.},
+        title        = {Uma análise preliminar das sesmarias nas Capitanias do Norte},
+        pages        = {231--242},
+        booktitle    = {A época moderna e o Brasil colonial},
+        editor       = {Silva, G. C. M.},
+        address      = {Maceió},
+        publisher    = {EDUFAL},
+        year         = {2019}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Ieee(bib)),
+        "C. M. O. Alveal, “Uma análise preliminar das sesmarias nas Capitanias do Norte,” in <em>A época moderna e o Brasil colonial</em>, G. C. M. Silva, Ed. Maceió: EDUFAL, 2019, pp. 231–242.",
+    );
+}
+
+#[test]
+fn prefixed_doi() {
+    let entries = r"
+    @article{Souza2020,
+        author  = {Souza, A.},
+        title   = {Sesmarias do Seridó},
+        journal = {Mneme},
+        year    = {2020},
+        doi     = {doi:10.1000/xyz}
+    }
+    @article{Silva2021,
+        author  = {Silva, M.},
+        title   = {Vilas do Norte},
+        journal = {Mneme},
+        year    = {2021},
+        doi     = {not a doi}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entries).expect("valid bibtex");
+
+    let bibs = bibtex.bibliographies();
+
+    assert_eq!(
+        format!("{}", Ieee(&bibs[0])),
+        r#"A. Souza, “Sesmarias do Seridó,” <em>Mneme</em>, 2020, <a href="https://doi.org/10.1000/xyz">https://doi.org/10.1000/xyz</a>."#,
+    );
+    assert_eq!(
+        format!("{}", Ieee(&bibs[1])),
+        "M. Silva, “Vilas do Norte,” <em>Mneme</em>, 2021.",
+    );
+}
+
+#[test]
+fn book_with_edition() {
+    let entries = r"
+    @book{Assuncao2004,
+        title     = {Negócios Jesuíticos},
+        subtitle  = {o cotidiano},
+        author    = {Assunção, P.},
+        year      = 2004,
+        publisher = {Edusp},
+        address   = {São Paulo},
+        edition   = 2
+    }
+    @inbook{Fragoso2001,
+        title     = {A formação da economia colonial},
+        author    = {Fragoso, J. A.},
+        booktitle = {O Antigo Regime nos trópicos},
+        publisher = {Civilização Brasileira},
+        address   = {Rio de Janeiro},
+        year      = 2001,
+        edition   = 3
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entries).expect("valid bibtex");
+
+    let bibs = bibtex.bibliographies();
+
+    assert_eq!(
+        format!("{}", Ieee(&bibs[0])),
+        "P. Assunção, <em>Negócios Jesuíticos: o cotidiano</em>, 2nd ed. São Paulo: Edusp, 2004.",
+    );
+    assert_eq!(
+        format!("{}", Ieee(&bibs[1])),
+        "J. A. Fragoso, “A formação da economia colonial,” in <em>O Antigo Regime nos trópicos</em>, 3rd ed. Rio de Janeiro: Civilização Brasileira, 2001.",
+    );
+}
+
+#[test]
+fn other_types() {
+    let entries = r"
+    @proceedings{EEH2016,
+        editor     = {Dias, P. O.},
+        title      = {Anais do Encontro Estadual de História},
+        location   = {Guarabira},
+        publisher  = {ANPUH-PB},
+        year       = {2016}
+    }
+    @movie{Central1998,
+        title      = {Central do Brasil},
+        director   = {Salles, Walter},
+        year       = {1998}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entries).expect("valid bibtex");
+
+    let bibs = bibtex.bibliographies();
+
+    assert_eq!(
+        format!("{}", Ieee(&bibs[0])),
+        "P. O. Dias, Ed., <em>Anais do Encontro Estadual de História</em>. Guarabira: ANPUH-PB, 2016.",
+    );
+    assert_eq!(format!("{}", Ieee(&bibs[1])), "“Central do Brasil.” 1998.");
+}
@@ -0,0 +1,146 @@
+use std::fmt::Display;
+
+use nom_bibtex::Bibliography;
+
+use crate::{abnt::pages::Pages, apa::date::Date, doi::Doi};
+
+pub use self::{ieee::Ieee, vancouver::Vancouver};
+
+mod ieee;
+mod vancouver;
+
+/// How the reference number is written at the start of each entry.
+#[derive(Clone, Copy)]
+pub enum Label {
+    /// `1. `, as used by Vancouver.
+    Period,
+    /// `[1] `, as used by IEEE.
+    Brackets,
+    /// `1 `, as used by the ABNT numeric system.
+    Plain,
+}
+
+/// A reference preceded by its number in citation order.
+pub struct Numbered<R> {
+    pub number: usize,
+    pub label: Label,
+    pub reference: R,
+}
+
+impl<R> Numbered<R> {
+    pub fn map<S>(self, f: impl FnOnce(R) -> S) -> Numbered<S> {
+        Numbered {
+            number: self.number,
+            label: self.label,
+            reference: f(self.reference),
+        }
+    }
+}
+
+impl<R: Display> Display for Numbered<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.label {
+            Label::Period => write!(f, "{}. {}", self.number, self.reference),
+            Label::Brackets => write!(f, "[{}] {}", self.number, self.reference),
+            Label::Plain => write!(f, "{} {}", self.number, self.reference),
+        }
+    }
+}
+
+/// The tags read by the numeric styles' templates.
+struct Fields<'b> {
+    author: &'b str,
+    editor: &'b str,
+    bookauthor: &'b str,
+    title: &'b str,
+    subtitle: Option<&'b str>,
+    booktitle: &'b str,
+    booksubtitle: Option<&'b str>,
+    journal: Option<&'b str>,
+    short_journal: Option<&'b str>,
+    volume: Option<&'b str>,
+    issue: Option<&'b str>,
+    edition: Option<&'b str>,
+    pages: Option<Pages<'b>>,
+    location: &'b str,
+    publisher: &'b str,
+    institution: &'b str,
+    thesis_type: &'b str,
+    site: Option<&'b str>,
+    url: Option<&'b str>,
+    url_date: Option<&'b str>,
+    doi: Option<Doi>,
+    date: Date<'b>,
+}
+
+impl<'b> Fields<'b> {
+    fn from_bib(b: &'b Bibliography) -> Self {
+        let mut fields = Fields {
+            author: "",
+            editor: "",
+            bookauthor: "",
+            title: "",
+            subtitle: None,
+            booktitle: "",
+            booksubtitle: None,
+            journal: None,
+            short_journal: None,
+            volume: None,
+            issue: None,
+            edition: None,
+            pages: None,
+            location: "",
+            publisher: "",
+            institution: "",
+            thesis_type: b.entry_type(),
+            site: None,
+            url: None,
+            url_date: None,
+            doi: None,
+            date: Date::default(),
+        };
+
+        for (k, v) in b.tags().iter() {
+            match k.as_str() {
+                "author" => fields.author = v.trim(),
+                "editor" | "organizer" => fields.editor = v.trim(),
+                "bookauthor" => fields.bookauthor = v.trim(),
+                "title" => fields.title = v.trim(),
+                "subtitle" => fields.subtitle = Some(v.trim()),
+                "booktitle" => fields.booktitle = v.trim(),
+                "eventtitle" if fields.booktitle.is_empty() => fields.booktitle = v.trim(),
+                "booksubtitle" => fields.booksubtitle = Some(v.trim()),
+                "journal" | "journaltitle" => fields.journal = Some(v.trim()),
+                "shortjournal" => fields.short_journal = Some(v.trim()),
+                "volume" => fields.volume = Some(v.trim()),
+                "issue" | "number" => fields.issue = Some(v.trim()),
+                "edition" => fields.edition = Some(v.trim()),
+                "page" | "pages" => fields.pages = Some(Pages::from_str(v.trim())),
+                "location" | "address" => fields.location = v.trim(),
+                "publisher" => fields.publisher = v.trim(),
+                "institution" | "school" => fields.institution = v.trim(),
+                "type" => fields.thesis_type = v.trim(),
+                "organization" | "maintitle" => fields.site = Some(v.trim()),
+                "url" => fields.url = Some(v.trim()),
+                "urldate" => fields.url_date = Some(v.trim()),
+                "doi" => fields.doi = Doi::parse(v),
+                "date" => fields.date = Date::parse(v),
+                "year" if fields.date.year.is_none() => fields.date = Date::from_year(v),
+                _ => continue,
+            }
+        }
+
+        fields
+    }
+}
+
+#[test]
+fn numbered() {
+    let n = Numbered {
+        number: 3,
+        label: Label::Brackets,
+        reference: "M. J. Rezende.",
+    };
+
+    assert_eq!(format!("{n}"), "[3] M. J. Rezende.");
+}
@@ -0,0 +1,481 @@
+use std::fmt::{Display, Formatter, Result};
+
+use nom_bibtex::Bibliography;
+
+use crate::{
+    abnt::{authors::split_name, join::Join, pages::Pages, tex, AND_REGEX},
+    apa::{date::Date, parts::Edition, sentence_case::SentenceCase},
+    html::{Anchor, DoiAnchor},
+};
+
+use super::Fields;
+
+/// Vancouver lists up to six authors before "et al.".
+const MAX_AUTHORS: usize = 6;
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// A bibliography entry rendered according to the Vancouver (ICMJE/NLM)
+/// style.
+#[repr(transparent)]
+pub struct Vancouver<'b>(pub &'b Bibliography);
+
+/// `Rezende MJ, Souza A`.
+struct Names<'a>(&'a str);
+
+/// `Rezende MJ`.
+struct Name<'a>(&'a str);
+
+/// Page ranges with the repeated leading digits dropped: `201-26`.
+struct ElidedPages<'p>(&'p Pages<'p>);
+
+/// `2019 Jul 19`.
+struct NlmDate<'d>(&'d Date<'d>);
+
+struct Title<'t>(&'t str, Option<&'t str>);
+
+impl<'a> Display for Names<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let names: Vec<_> = AND_REGEX.split(self.0).map(str::trim).collect();
+
+        if names.len() > MAX_AUTHORS {
+            let names = names[..MAX_AUTHORS].iter().copied().map(Name);
+            write!(f, "{}, et al", Join::new(", ", names))
+        } else {
+            write!(f, "{}", Join::new(", ", names.into_iter().map(Name)))
+        }
+    }
+}
+
+impl<'a> Display for Name<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let (family, given) = match split_name(self.0) {
+            Some(pair) => pair,
+            None => return write!(f, "{}", tex::NoBraces(self.0)),
+        };
+
+        write!(f, "{} ", tex::NoBraces(family.trim()))?;
+
+        let initials = given
+            .split(|c: char| c.is_whitespace() || c == '-' || c == '.')
+            .filter_map(|part| part.chars().find(|c| c.is_alphabetic()))
+            .filter(|c| c.is_uppercase());
+
+        for c in initials {
+            write!(f, "{c}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'p> Display for ElidedPages<'p> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Pages::Single(page) => write!(f, "{page}"),
+            Pages::Range(first, last) => {
+                let numeric = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+
+                if first.len() == last.len() && numeric(first) && numeric(last) {
+                    let common = first
+                        .chars()
+                        .zip(last.chars())
+                        .take_while(|(a, b)| a == b)
+                        .count()
+                        .min(last.len() - 1);
+
+                    write!(f, "{first}-{}", &last[common..])
+                } else {
+                    write!(f, "{first}-{last}")
+                }
+            }
+            Pages::List(pages) => write!(f, "{}", Join::new(",", pages.iter().map(ElidedPages))),
+        }
+    }
+}
+
+impl<'d> Display for NlmDate<'d> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let month = self
+            .0
+            .month
+            .filter(|m| (1..=12).contains(m))
+            .map(|m| MONTHS[(m - 1) as usize]);
+
+        match (self.0.year, month, self.0.day) {
+            (Some(year), Some(month), Some(day)) => write!(f, "{year} {month} {day}"),
+            (Some(year), Some(month), None) => write!(f, "{year} {month}"),
+            (Some(year), None, _) => write!(f, "{year}"),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'t> Display for Title<'t> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", SentenceCase(self.0))?;
+
+        if let Some(subtitle) = self.1 {
+            write!(f, ": {}", SentenceCase(subtitle))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_authors(f: &mut Formatter<'_>, b: &Fields) -> Result {
+    if !b.author.is_empty() {
+        write!(f, "{}. ", Names(b.author))
+    } else if !b.editor.is_empty() {
+        write!(f, "{}, {}. ", Names(b.editor), editors(b.editor))
+    } else {
+        Ok(())
+    }
+}
+
+fn editors(names: &str) -> &'static str {
+    if AND_REGEX.is_match(names) {
+        "editors"
+    } else {
+        "editor"
+    }
+}
+
+fn write_publication(f: &mut Formatter<'_>, b: &Fields) -> Result {
+    if !b.location.is_empty() {
+        write!(f, "{}: ", tex::Text(b.location))?;
+    }
+
+    if !b.publisher.is_empty() {
+        write!(f, "{}; ", tex::Text(b.publisher))?;
+    }
+
+    write!(f, "{}.", NlmDate(&b.date))
+}
+
+fn write_doi(f: &mut Formatter<'_>, b: &Fields) -> Result {
+    if let Some(doi) = &b.doi {
+        write!(f, " {}", DoiAnchor(doi))?;
+    }
+
+    Ok(())
+}
+
+fn article(f: &mut Formatter<'_>, b: &Fields) -> Result {
+    write_authors(f, b)?;
+    write!(f, "{}.", Title(b.title, b.subtitle))?;
+
+    if let Some(journal) = b.short_journal.or(b.journal) {
+        write!(f, " {}.", tex::Text(journal))?;
+    }
+
+    write!(f, " {}", NlmDate(&b.date))?;
+
+    if b.volume.is_some() || b.issue.is_some() {
+        f.write_str(";")?;
+    }
+
+    if let Some(volume) = b.volume {
+        write!(f, "{volume}")?;
+    }
+
+    if let Some(issue) = b.issue {
+        write!(f, "({issue})")?;
+    }
+
+    if let Some(pages) = &b.pages {
+        write!(f, ":{}", ElidedPages(pages))?;
+    }
+
+    f.write_str(".")?;
+
+    write_doi(f, b)
+}
+
+fn book(f: &mut Formatter<'_>, b: &Fields) -> Result {
+    write_authors(f, b)?;
+    write!(f, "{}. ", Title(b.title, b.subtitle))?;
+
+    if let Some(edition) = b.edition {
+        write!(f, "{} ", Edition(edition))?;
+    }
+
+    write_publication(f, b)?;
+    write_doi(f, b)
+}
+
+fn chapter(f: &mut Formatter<'_>, b: &Fields) -> Result {
+    if !b.author.is_empty() {
+        write!(f, "{}. ", Names(b.author))?;
+    }
+
+    write!(f, "{}. In: ", Title(b.title, b.subtitle))?;
+
+    if !b.bookauthor.is_empty() {
+        write!(f, "{}. ", Names(b.bookauthor))?;
+    } else if !b.editor.is_empty() {
+        write!(f, "{}, {}. ", Names(b.editor), editors(b.editor))?;
+    }
+
+    write!(f, "{}. ", Title(b.booktitle, b.booksubtitle))?;
+
+    if let Some(edition) = b.edition {
+        write!(f, "{} ", Edition(edition))?;
+    }
+
+    write_publication(f, b)?;
+
+    if let Some(pages) = &b.pages {
+        write!(f, " p. {}.", ElidedPages(pages))?;
+    }
+
+    write_doi(f, b)
+}
+
+fn thesis(f: &mut Formatter<'_>, b: &Fields) -> Result {
+    let thesis_type = match b.thesis_type {
+        "phdthesis" => "dissertation",
+        "mathesis" | "mastersthesis" => "master's thesis",
+        "thesis" => "thesis",
+        t => t,
+    };
+
+    write_authors(f, b)?;
+    write!(
+        f,
+        "{} [{}]. ",
+        Title(b.title, b.subtitle),
+        tex::Text(thesis_type)
+    )?;
+
+    if !b.location.is_empty() {
+        write!(f, "{}: ", tex::Text(b.location))?;
+    }
+
+    if !b.institution.is_empty() {
+        write!(f, "{}; ", tex::Text(b.institution))?;
+    }
+
+    write!(f, "{}.", NlmDate(&b.date))
+}
+
+fn online(f: &mut Formatter<'_>, b: &Fields) -> Result {
+    write_authors(f, b)?;
+    write!(f, "{}", Title(b.title, b.subtitle))?;
+
+    if b.url.is_some() {
+        f.write_str(" [Internet]")?;
+    }
+
+    f.write_str(".")?;
+
+    if let Some(site) = b.site.or(Some(b.publisher).filter(|p| !p.is_empty())) {
+        write!(f, " {}", tex::Text(site))?;
+
+        if b.date.year.is_some() {
+            f.write_str(";")?;
+        } else {
+            f.write_str(".")?;
+        }
+    }
+
+    if b.date.year.is_some() {
+        write!(f, " {}", NlmDate(&b.date))?;
+    }
+
+    if let Some(url_date) = b.url_date {
+        write!(f, " [cited {}]", NlmDate(&Date::parse(url_date)))?;
+    }
+
+    if b.date.year.is_some() || b.url_date.is_some() {
+        f.write_str(".")?;
+    }
+
+    if let Some(url) = b.url {
+        write!(f, " Available from: {}", Anchor::url(url))?;
+    }
+
+    Ok(())
+}
+
+impl<'b> Display for Vancouver<'b> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let fields = Fields::from_bib(self.0);
+
+        match self.0.entry_type() {
+            "article" => article(f, &fields),
+            "book" | "mvbook" | "collection" | "mvcollection" | "proceedings" | "reference"
+            | "mvreference" => book(f, &fields),
+            "inbook" | "incollection" | "inproceedings" | "inreference" => chapter(f, &fields),
+            "thesis" | "phdthesis" | "mastersthesis" => thesis(f, &fields),
+            // Other types, such as movies and periodicals, are written like a
+            // stand-alone web document.
+            _ => online(f, &fields),
+        }
+    }
+}
+
+#[test]
+fn names() {
+    assert_eq!(
+        format!("{}", Names("Rezende, M. J. and Motter, Maria de Lourdes")),
+        "Rezende MJ, Motter ML"
+    );
+
+    let many = "A, A. and B, B. and C, C. and D, D. and E, E. and F, F. and G, G.";
+    assert_eq!(
+        format!("{}", Names(many)),
+        "A A, B B, C C, D D, E E, F F, et al"
+    );
+}
+
+#[test]
+fn elided_pages() {
+    assert_eq!(
+        format!("{}", ElidedPages(&Pages::Range("201", "226"))),
+        "201-26"
+    );
+    assert_eq!(
+        format!("{}", ElidedPages(&Pages::Range("5", "131"))),
+        "5-131"
+    );
+    assert_eq!(
+        format!("{}", ElidedPages(&Pages::Range("100", "101"))),
+        "100-1"
+    );
+}
+
+#[test]
+fn article_from_bib() {
+    let entry = r"
+    @article{Rezende2001,
+        author   = {Rezende, M. J.},
+        title    = {Os sertões e os (des)caminhos da mudança social no {Brasil}},
+        journal  = {Tempo Social},
+        volume   = {13},
+        number   = {2},
+        year     = {2001},
+        pages    = {201--226}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Vancouver(bib)),
+        "Rezende MJ. Os sertões e os (des)caminhos da mudança social no Brasil. Tempo Social. 2001;13(2):201-26.",
+    );
+}
+
+#[test]
+fn book_from_bib() {
+    let entry = r"
+    @book{Assuncao2004,
+        title        = {Negócios {Jesuíticos}},
+        author       = {Assunção, P.},
+        year         = 2004,
+        publisher    = {Editora da Universidade de São Paulo},
+        address      = {São Paulo},
+        subtitle     = {o cotidiano da administração dos bens divinos},
+        edition      = 2
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Vancouver(bib)),
+        "Assunção P. Negócios Jesuíticos: O cotidiano da administração dos bens divinos. 2nd ed. São Paulo: Editora da Universidade de São Paulo; 2004.",
+    );
+}
+
+#[test]
+fn online_from_bib() {
+    let entry = "
+    @online{MarizCPDOC,
+        title        = {{Dinarte de Medeiros Mariz}},
+        organization = {CPDOC},
+        url          = {http://www.fgv.br/cpdoc},
+        urldate      = {2019-07-19}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entry).expect("valid bibtex");
+
+    let bib = &bibtex.bibliographies()[0];
+
+    assert_eq!(
+        format!("{}", Vancouver(bib)),
+        r#"Dinarte de Medeiros Mariz [Internet]. CPDOC. [cited 2019 Jul 19]. Available from: <a href="http://www.fgv.br/cpdoc">http://www.fgv.br/cpdoc</a>"#,
+    );
+}
+
+#[test]
+fn prefixed_doi() {
+    let entries = r"
+    @article{Souza2020,
+        author  = {Souza, A.},
+        title   = {Sesmarias do {Seridó}},
+        journal = {Mneme},
+        year    = {2020},
+        doi     = {doi:10.1000/xyz}
+    }
+    @article{Silva2021,
+        author  = {Silva, M.},
+        title   = {Vilas do Norte},
+        journal = {Mneme},
+        year    = {2021},
+        doi     = {not a doi}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entries).expect("valid bibtex");
+
+    let bibs = bibtex.bibliographies();
+
+    assert_eq!(
+        format!("{}", Vancouver(&bibs[0])),
+        r#"Souza A. Sesmarias do Seridó. Mneme. 2020. <a href="https://doi.org/10.1000/xyz">https://doi.org/10.1000/xyz</a>"#,
+    );
+    assert_eq!(
+        format!("{}", Vancouver(&bibs[1])),
+        "Silva M. Vilas do norte. Mneme. 2021.",
+    );
+}
+
+#[test]
+fn other_types() {
+    let entries = r"
+    @proceedings{EEH2016,
+        editor     = {Dias, P. O.},
+        title      = {Anais do {Encontro Estadual de História}},
+        location   = {Guarabira},
+        publisher  = {ANPUH-PB},
+        year       = {2016}
+    }
+    @movie{Central1998,
+        title      = {Central do {Brasil}},
+        director   = {Salles, Walter},
+        year       = {1998}
+    }
+    ";
+
+    let bibtex = nom_bibtex::Bibtex::parse(entries).expect("valid bibtex");
+
+    let bibs = bibtex.bibliographies();
+
+    assert_eq!(
+        format!("{}", Vancouver(&bibs[0])),
+        "Dias PO, editor. Anais do Encontro Estadual de História. Guarabira: ANPUH-PB; 2016.",
+    );
+    assert_eq!(
+        format!("{}", Vancouver(&bibs[1])),
+        "Central do Brasil. 1998."
+    );
+}